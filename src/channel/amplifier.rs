@@ -0,0 +1,161 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::macros::message_handler;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+/// Memoryless amplifier characteristic
+#[derive(Clone, Copy, Debug)]
+pub enum AmplifierModel {
+    /// Saleh TWTA model with the AM/AM `alpha_a r / (1 + beta_a r^2)` and the AM/PM
+    /// `alpha_p r^2 / (1 + beta_p r^2)` in rad
+    Saleh {
+        alpha_a: f32,
+        beta_a: f32,
+        alpha_p: f32,
+        beta_p: f32,
+    },
+    /// Rapp SSPA model with unit gain and saturation amplitude, with the AM/AM
+    /// `r / (1 + r^(2p))^(1/(2p))` for the `smoothness` `p` and no AM/PM
+    Rapp { smoothness: f32 },
+}
+
+impl AmplifierModel {
+    /// Saleh model with the TWTA parameters of Saleh's original paper
+    pub fn twta() -> Self {
+        AmplifierModel::Saleh {
+            alpha_a: 2.1587,
+            beta_a: 1.1517,
+            alpha_p: 4.0033,
+            beta_p: 9.104,
+        }
+    }
+
+    /// Input and output amplitude at saturation
+    fn saturation(&self) -> Option<(f32, f32)> {
+        match *self {
+            AmplifierModel::Saleh {
+                alpha_a,
+                beta_a,
+                alpha_p,
+                beta_p,
+            } => {
+                let valid = alpha_a > 0.0 && beta_a > 0.0 && beta_p >= 0.0 && alpha_p.is_finite();
+                valid.then(|| (1.0 / beta_a.sqrt(), alpha_a / (2.0 * beta_a.sqrt())))
+            }
+            AmplifierModel::Rapp { smoothness } => (smoothness > 0.0).then_some((1.0, 1.0)),
+        }
+    }
+
+    /// Output amplitude and phase shift in rad for the input amplitude `r`
+    fn response(&self, r: f32) -> (f32, f32) {
+        match *self {
+            AmplifierModel::Saleh {
+                alpha_a,
+                beta_a,
+                alpha_p,
+                beta_p,
+            } => {
+                let r2 = r * r;
+                (
+                    alpha_a * r / (1.0 + beta_a * r2),
+                    alpha_p * r2 / (1.0 + beta_p * r2),
+                )
+            }
+            AmplifierModel::Rapp { smoothness } => {
+                let p2 = 2.0 * smoothness;
+                (r / (1.0 + r.powf(p2)).powf(1.0 / p2), 0.0)
+            }
+        }
+    }
+}
+
+/// Nonlinear amplifier with AM/AM and AM/PM conversion.
+///
+/// The input is expected with unit mean power, as produced by the
+/// [`Transmitter`](crate::dvb::Transmitter), and is driven at the input back-off `ibo` in dB
+/// below the input saturation power. The output is scaled such that saturation corresponds to
+/// unit output power, so its mean power shows the output back-off. The input back-off can be
+/// updated on the `ibo` message port.
+pub struct Amplifier {
+    model: AmplifierModel,
+    /// Input amplitude at saturation
+    input_saturation: f32,
+    /// Output amplitude at saturation
+    output_saturation: f32,
+    /// Input gain for the current back-off
+    gain: f32,
+}
+
+impl Amplifier {
+    pub fn new(model: AmplifierModel, ibo: f32) -> Option<Block> {
+        let (input_saturation, output_saturation) = model.saturation()?;
+        Some(Block::new(
+            BlockMetaBuilder::new("Amplifier").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_input("ibo", Self::ibo)
+                .build(),
+            Self {
+                model,
+                input_saturation,
+                output_saturation,
+                gain: Self::gain(input_saturation, ibo),
+            },
+        ))
+    }
+
+    fn gain(input_saturation: f32, ibo: f32) -> f32 {
+        input_saturation * 10f32.powf(-ibo / 20.0)
+    }
+
+    #[message_handler]
+    async fn ibo(
+        &mut self,
+        _io: &mut WorkIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+        p: Pmt,
+    ) -> Result<Pmt> {
+        let ibo = match p {
+            Pmt::F32(v) => v,
+            Pmt::F64(v) => v as f32,
+            _ => return Ok(Pmt::InvalidValue),
+        };
+        self.gain = Self::gain(self.input_saturation, ibo);
+        Ok(Pmt::Ok)
+    }
+}
+
+#[async_trait]
+impl Kernel for Amplifier {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+
+        let m = std::cmp::min(i.len(), o.len());
+        for (x, y) in i[..m].iter().zip(o[..m].iter_mut()) {
+            let (r, theta) = x.to_polar();
+            let (a, phi) = self.model.response(r * self.gain);
+            *y = Complex32::from_polar(a / self.output_saturation, theta + phi);
+        }
+
+        sio.input(0).consume(m);
+        sio.output(0).produce(m);
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use std::f32::consts::PI;
+
+use fastrand::Rng;
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::macros::message_handler;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use crate::dvb::config::{CodeRate, Constellation};
+
+/// Circularly symmetric complex Gaussian sample with `E|n|^2 = 1`
+pub(super) fn gaussian(rng: &mut Rng) -> Complex32 {
+    // Box-Muller on (0, 1] to avoid the logarithm of zero
+    let r = (-(1.0 - rng.f32()).ln()).sqrt();
+    Complex32::from_polar(r, 2.0 * PI * rng.f32())
+}
+
+/// Additive white Gaussian noise channel.
+///
+/// The input is expected at `sps` samples per symbol with unit mean power, as produced by the
+/// [`Transmitter`](crate::dvb::Transmitter), so that the noise power in the symbol bandwidth
+/// matches the Es/N0. The noise is drawn from a [`Rng`] seeded with `seed`, which makes runs
+/// repeatable. The Es/N0 in dB can be updated on the `esn0` message port.
+pub struct Awgn {
+    rng: Rng,
+    sps: usize,
+    /// Standard deviation of the complex noise samples
+    sigma: f32,
+}
+
+impl Awgn {
+    pub fn new(esn0: f32, sps: usize, seed: u64) -> Option<Block> {
+        if sps == 0 {
+            return None;
+        }
+        Some(Block::new(
+            BlockMetaBuilder::new("AWGN").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_input("esn0", Self::esn0)
+                .build(),
+            Self {
+                rng: Rng::with_seed(seed),
+                sps,
+                sigma: Self::sigma(esn0, sps),
+            },
+        ))
+    }
+
+    /// AWGN channel at the Eb/N0 in dB of the information bits of the given MODCOD.
+    ///
+    /// Only the nominal code rate is accounted for, not the PLFRAME overhead.
+    pub fn from_ebn0(
+        ebn0: f32,
+        rate: CodeRate,
+        constellation: Constellation,
+        sps: usize,
+        seed: u64,
+    ) -> Option<Block> {
+        Self::new(ebn0_to_esn0(ebn0, rate, constellation)?, sps, seed)
+    }
+
+    fn sigma(esn0: f32, sps: usize) -> f32 {
+        (sps as f32 * 10f32.powf(-esn0 / 10.0)).sqrt()
+    }
+
+    #[message_handler]
+    async fn esn0(
+        &mut self,
+        _io: &mut WorkIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+        p: Pmt,
+    ) -> Result<Pmt> {
+        let esn0 = match p {
+            Pmt::F32(v) => v,
+            Pmt::F64(v) => v as f32,
+            _ => return Ok(Pmt::InvalidValue),
+        };
+        self.sigma = Self::sigma(esn0, self.sps);
+        Ok(Pmt::Ok)
+    }
+}
+
+/// Es/N0 in dB for the Eb/N0 in dB of the information bits of the given MODCOD
+fn ebn0_to_esn0(ebn0: f32, rate: CodeRate, constellation: Constellation) -> Option<f32> {
+    let spread = if matches!(constellation, Constellation::ModBpskSf2) {
+        2.0
+    } else {
+        1.0
+    };
+    let bits = constellation.bits_per_symbol() as f32 * rate.value()? / spread;
+    (bits > 0.0).then(|| ebn0 + 10.0 * bits.log10())
+}
+
+#[async_trait]
+impl Kernel for Awgn {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+
+        let m = std::cmp::min(i.len(), o.len());
+        for (x, y) in i[..m].iter().zip(o[..m].iter_mut()) {
+            *y = x + gaussian(&mut self.rng) * self.sigma;
+        }
+
+        sio.input(0).consume(m);
+        sio.output(0).produce(m);
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
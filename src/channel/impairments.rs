@@ -0,0 +1,343 @@
+use std::f64::consts::PI;
+
+use fastrand::Rng;
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::{Complex32, Complex64};
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::awgn::gaussian;
+use crate::interpolation::interpolate;
+
+/// Reference mask for the aggregate phase noise of a DVB-S2 link (ETSI TR 102 376), as pairs of
+/// offset frequency in Hz and SSB phase noise in dBc/Hz
+pub const DVBS2_PHASE_NOISE_MASK: [(f32, f32); 6] = [
+    (1e2, -25.0),
+    (1e3, -50.0),
+    (1e4, -73.0),
+    (1e5, -92.5),
+    (1e6, -102.0),
+    (1e7, -112.0),
+];
+
+/// Number of first-order sections per decade of the phase noise model
+const SECTIONS_PER_DECADE: f64 = 5.0;
+/// Number of frequencies per decade the phase noise model is fitted at
+const FIT_POINTS_PER_DECADE: f64 = 20.0;
+const FIT_ITERATIONS: usize = 2000;
+
+/// SSB phase noise of `mask` in dBc/Hz at `f` Hz, interpolated linearly over the logarithm of the
+/// frequency and constant outside the mask
+fn mask_level(mask: &[(f32, f32)], f: f64) -> f64 {
+    let (first, last) = (mask[0], mask[mask.len() - 1]);
+    if f <= first.0 as f64 {
+        return first.1 as f64;
+    }
+    mask.windows(2)
+        .find(|w| f <= w[1].0 as f64)
+        .map(|w| {
+            let (f0, f1) = ((w[0].0 as f64).log10(), (w[1].0 as f64).log10());
+            let t = (f.log10() - f0) / (f1 - f0);
+            w[0].1 as f64 + t * (w[1].1 - w[0].1) as f64
+        })
+        .unwrap_or(last.1 as f64)
+}
+
+/// First- or second-order lowpass section of the phase noise model
+struct Section {
+    pole: f64,
+    /// Standard deviation of the white input
+    sigma: f64,
+    second_order: bool,
+    state: [f64; 2],
+}
+
+/// Phase noise following a mask, generated as the sum of white Gaussian noise filtered by
+/// first- and second-order lowpass sections with log-spaced corner frequencies and a white floor.
+/// The second-order sections allow slopes steeper than 20 dB per decade.
+struct PhaseNoise {
+    rng: Rng,
+    sections: Vec<Section>,
+    /// Standard deviation of the white floor
+    floor: f64,
+}
+
+impl PhaseNoise {
+    fn new(mask: &[(f32, f32)], sample_rate: f64, seed: u64) -> Self {
+        let nyquist = sample_rate / 2.0;
+        let start = (mask[0].0 as f64).log10();
+        let decades = (nyquist.log10() - start).max(0.0);
+        let poles: Vec<f64> = (0..(decades * SECTIONS_PER_DECADE) as usize)
+            .map(|k| 10f64.powf(start + k as f64 / SECTIONS_PER_DECADE))
+            .map(|f| (-2.0 * PI * f / sample_rate).exp())
+            .collect();
+        let freqs: Vec<f64> = (0..=(decades * FIT_POINTS_PER_DECADE) as usize)
+            .map(|j| {
+                10f64
+                    .powf(start + j as f64 / FIT_POINTS_PER_DECADE)
+                    .min(nyquist)
+            })
+            .collect();
+        // Two-sided phase PSD in rad^2/Hz equals the SSB phase noise
+        let target: Vec<f64> = freqs
+            .iter()
+            .map(|&f| 10f64.powf(mask_level(mask, f) / 10.0))
+            .collect();
+
+        // PSD of each section and the floor at the fit frequencies, normalized to one at DC
+        let sections: Vec<(f64, bool)> = poles
+            .iter()
+            .flat_map(|&a| [(a, false), (a, true)])
+            .collect();
+        let basis: Vec<Vec<f64>> = sections
+            .iter()
+            .map(|&(a, second_order)| {
+                freqs
+                    .iter()
+                    .map(|&f| {
+                        let z = Complex64::from_polar(a, -2.0 * PI * f / sample_rate);
+                        let h = (1.0 - a).powi(2) / (Complex64::new(1.0, 0.0) - z).norm_sqr();
+                        if second_order {
+                            h * h
+                        } else {
+                            h
+                        }
+                    })
+                    .collect()
+            })
+            .chain(std::iter::once(vec![1.0; freqs.len()]))
+            .collect();
+
+        // Multiplicative updates keep the levels positive and fit the relative error
+        let mut levels: Vec<f64> = basis.iter().map(|_| target[0] * 1e-3).collect();
+        for _ in 0..FIT_ITERATIONS {
+            let psd: Vec<f64> = (0..freqs.len())
+                .map(|j| basis.iter().zip(&levels).map(|(b, c)| b[j] * c).sum())
+                .collect();
+            for (b, c) in basis.iter().zip(levels.iter_mut()) {
+                let (num, den) = (0..freqs.len()).fold((0.0, 0.0), |(n, d), j| {
+                    let w = b[j] / psd[j];
+                    (n + w * target[j] / psd[j], d + w)
+                });
+                *c *= num / den;
+            }
+        }
+
+        let floor = (levels.pop().unwrap() * sample_rate).sqrt();
+        let sections = sections
+            .into_iter()
+            .zip(&levels)
+            .map(|((pole, second_order), &c)| Section {
+                pole,
+                sigma: (1.0 - pole) * (c * sample_rate).sqrt(),
+                second_order,
+                state: [0.0; 2],
+            })
+            .collect();
+        Self {
+            rng: Rng::with_seed(seed),
+            sections,
+            floor,
+        }
+    }
+
+    fn normal(rng: &mut Rng) -> f64 {
+        gaussian(rng).re as f64 * std::f64::consts::SQRT_2
+    }
+
+    fn next(&mut self) -> f64 {
+        let mut phase = self.floor * Self::normal(&mut self.rng);
+        for s in self.sections.iter_mut() {
+            s.state[0] = s.pole * s.state[0] + s.sigma * Self::normal(&mut self.rng);
+            if s.second_order {
+                s.state[1] = s.pole * s.state[1] + (1.0 - s.pole) * s.state[0];
+                phase += s.state[1];
+            } else {
+                phase += s.state[0];
+            }
+        }
+        phase
+    }
+}
+
+/// Builder for the [`Impairments`] block.
+///
+/// All impairments are disabled by default.
+pub struct ImpairmentsBuilder {
+    sample_rate: f32,
+    phase_noise: Option<(Vec<(f32, f32)>, u64)>,
+    frequency: f32,
+    drift: f32,
+    clock_offset: f32,
+    iq_gain: f32,
+    iq_phase: f32,
+}
+
+impl ImpairmentsBuilder {
+    /// Impairments of a stream at `sample_rate` Hz
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            phase_noise: None,
+            frequency: 0.0,
+            drift: 0.0,
+            clock_offset: 0.0,
+            iq_gain: 0.0,
+            iq_phase: 0.0,
+        }
+    }
+
+    /// Phase noise following `mask`, pairs of offset frequency in Hz and SSB phase noise in
+    /// dBc/Hz with increasing offsets, drawn from a [`Rng`] seeded with `seed`
+    pub fn phase_noise(mut self, mask: &[(f32, f32)], seed: u64) -> Self {
+        self.phase_noise = Some((mask.to_vec(), seed));
+        self
+    }
+
+    /// Carrier frequency offset in Hz, changing by `drift` Hz/s
+    pub fn frequency(mut self, frequency: f32, drift: f32) -> Self {
+        self.frequency = frequency;
+        self.drift = drift;
+        self
+    }
+
+    /// Relative sample clock offset in ppm of the output to the input samples
+    pub fn clock_offset(mut self, ppm: f32) -> Self {
+        self.clock_offset = ppm;
+        self
+    }
+
+    /// Gain imbalance in dB and phase imbalance in degrees of the Q branch
+    pub fn iq_imbalance(mut self, gain: f32, phase: f32) -> Self {
+        self.iq_gain = gain;
+        self.iq_phase = phase;
+        self
+    }
+
+    pub fn build(self) -> Option<Block> {
+        if !(self.sample_rate > 0.0 && self.clock_offset.abs() < 1e6) {
+            return None;
+        }
+        let sample_rate = self.sample_rate as f64;
+        let phase_noise = match &self.phase_noise {
+            Some((mask, seed)) => {
+                let valid =
+                    !mask.is_empty() && mask[0].0 > 0.0 && mask.windows(2).all(|w| w[0].0 < w[1].0);
+                if !valid {
+                    return None;
+                }
+                Some(PhaseNoise::new(mask, sample_rate, *seed))
+            }
+            None => None,
+        };
+        let phase = (self.iq_phase as f64).to_radians();
+        Some(Block::new(
+            BlockMetaBuilder::new("Impairments").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Impairments>::new().build(),
+            Impairments {
+                phase_noise,
+                frequency: 2.0 * PI * self.frequency as f64 / sample_rate,
+                drift: 2.0 * PI * self.drift as f64 / (sample_rate * sample_rate),
+                phase: 0.0,
+                iq: (
+                    10f32.powf(self.iq_gain / 20.0) * phase.cos() as f32,
+                    10f32.powf(self.iq_gain / 20.0) * phase.sin() as f32,
+                ),
+                step: 1.0 / (1.0 + self.clock_offset as f64 * 1e-6),
+                history: vec![Complex32::new(0.0, 0.0)],
+                time: 1.0,
+            },
+        ))
+    }
+}
+
+/// Channel impairments of a satellite link.
+///
+/// In this order, the samples are rotated by the carrier offset and the phase noise, distorted
+/// by the IQ imbalance and resampled with cubic interpolation to model the sample clock offset.
+pub struct Impairments {
+    phase_noise: Option<PhaseNoise>,
+    /// Carrier offset in rad/sample
+    frequency: f64,
+    /// Change of the carrier offset in rad/sample per sample
+    drift: f64,
+    /// Carrier phase in rad
+    phase: f64,
+    /// Gain times cosine and sine of the phase imbalance of the Q branch
+    iq: (f32, f32),
+    /// Input samples per output sample
+    step: f64,
+    /// Impaired input samples not yet behind the output time
+    history: Vec<Complex32>,
+    /// Time of the next output sample in input samples, relative to `history[0]`
+    time: f64,
+}
+
+impl Impairments {
+    fn impair(&mut self, x: Complex32) -> Complex32 {
+        let noise = self.phase_noise.as_mut().map_or(0.0, |p| p.next());
+        let y = x * Complex32::from_polar(1.0, (self.phase + noise) as f32);
+        self.phase = (self.phase + self.frequency + PI).rem_euclid(2.0 * PI) - PI;
+        self.frequency += self.drift;
+        Complex32::new(y.re, self.iq.0 * y.im - self.iq.1 * y.re)
+    }
+
+    fn output_ready(&self) -> bool {
+        (self.time as usize) + 2 < self.history.len()
+    }
+
+    fn next_output(&mut self) -> Complex32 {
+        let n = self.time as usize;
+        let y = interpolate(&self.history, n, self.time.fract() as f32);
+        self.time += self.step;
+        let drop = (self.time as usize).max(1) - 1;
+        self.history.drain(..drop);
+        self.time -= drop as f64;
+        y
+    }
+}
+
+#[async_trait]
+impl Kernel for Impairments {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        'outer: loop {
+            while self.output_ready() {
+                if produced == o.len() {
+                    break 'outer;
+                }
+                o[produced] = self.next_output();
+                produced += 1;
+            }
+            if consumed == i.len() {
+                break;
+            }
+            let y = self.impair(i[consumed]);
+            self.history.push(y);
+            consumed += 1;
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished = sio.input(0).finished() && consumed == i.len();
+        Ok(())
+    }
+}
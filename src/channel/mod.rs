@@ -0,0 +1,7 @@
+mod amplifier;
+mod awgn;
+mod impairments;
+
+pub use amplifier::{Amplifier, AmplifierModel};
+pub use awgn::Awgn;
+pub use impairments::{Impairments, ImpairmentsBuilder, DVBS2_PHASE_NOISE_MASK};
@@ -0,0 +1,70 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+/// Smallest power estimate, which bounds the gain on silent input
+const MIN_POWER: f32 = 1e-12;
+
+/// Automatic gain control to unit mean power.
+///
+/// The input power is tracked by an exponential moving average with the given `rate` per sample
+/// and the samples are scaled by its inverse square root. Unlike an AGC on the magnitude, this
+/// keeps the APSK rings at the radii the demapper expects.
+pub struct Agc {
+    rate: f32,
+    /// Mean input power, or `None` before the first sample
+    power: Option<f32>,
+}
+
+impl Agc {
+    pub fn new(rate: f32) -> Option<Block> {
+        if !(rate > 0.0 && rate <= 1.0) {
+            return None;
+        }
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_AGC").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self { rate, power: None },
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for Agc {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+
+        let m = std::cmp::min(i.len(), o.len());
+        for (x, y) in i[..m].iter().zip(o[..m].iter_mut()) {
+            let p = x.norm_sqr();
+            let power = match self.power {
+                Some(power) => power + self.rate * (p - power),
+                None => p,
+            }
+            .max(MIN_POWER);
+            self.power = Some(power);
+            *y = x / power.sqrt();
+        }
+
+        sio.input(0).consume(m);
+        sio.output(0).produce(m);
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
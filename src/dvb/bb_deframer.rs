@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::log::warn;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::bb_header::{crc8, BB_HEADER_LENGTH_BITS, TS_PACKET_SIZE, TS_SYNC_BYTE};
+use super::bch_encoder::BchCode;
+use super::config::{CodeRate, Framesize};
+
+/// Transport error indicator in the second byte of a TS packet
+const TS_TRANSPORT_ERROR: u8 = 0x80;
+/// Tag on the sync byte of a packet whose CRC-8 could not be checked
+const CRC_UNCHECKED_TAG: &str = "crc_unchecked";
+
+fn pack_bits(bits: &[u8]) -> Vec<u8> {
+    bits.chunks_exact(8)
+        .map(|b| b.iter().fold(0, |byte, &bit| (byte << 1) | (bit & 1)))
+        .collect()
+}
+
+/// Inverse of the [`BbHeader`](super::BbHeader) on descrambled BBFRAMEs.
+///
+/// Takes the `kbch` bits of a BBFRAME, one per byte, checks and parses the BBHEADER and outputs
+/// the TS packets of the DATA FIELD with their sync byte restored. Frames with a corrupted or
+/// unsupported BBHEADER are dropped and the packet being reassembled with them. Packets failing
+/// their CRC-8 are output with the transport error indicator set. The CRC-8 of the last packet of
+/// the stream would only follow in the next packet, so it is output unchecked when the input
+/// finishes, with a `crc_unchecked` string tag on its sync byte.
+///
+/// The numbers of BBHEADER and packet CRC failures are posted on the `crc_errors` message port as
+/// a map with the keys `header` and `packet` whenever they change.
+pub struct BbDeframer {
+    kbch: usize,
+    /// Reassembling packets
+    synced: bool,
+    /// Position inside the current user packet
+    count: usize,
+    /// Packet without its sync byte
+    current: Vec<u8>,
+    /// Complete packet waiting for its CRC-8 at the start of the next one
+    pending: Option<Vec<u8>>,
+    header_errors: usize,
+    packet_errors: usize,
+    output: Vec<u8>,
+    /// Number of `output` bytes already produced
+    pos: usize,
+    /// Position in `output` of the packet flushed without CRC check
+    unchecked: Option<usize>,
+}
+
+impl BbDeframer {
+    pub fn new(framesize: Framesize, rate: CodeRate) -> Option<Block> {
+        let kbch = BchCode::dvb(framesize, rate)?.k();
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_BB_Deframer").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_output("crc_errors")
+                .build(),
+            Self {
+                kbch,
+                synced: false,
+                count: 0,
+                current: Vec::with_capacity(TS_PACKET_SIZE - 1),
+                pending: None,
+                header_errors: 0,
+                packet_errors: 0,
+                output: Vec::new(),
+                pos: 0,
+                unchecked: None,
+            },
+        ))
+    }
+
+    fn emit(&mut self, packet: &[u8], valid: bool) {
+        let start = self.output.len();
+        self.output.push(TS_SYNC_BYTE);
+        self.output.extend_from_slice(packet);
+        if !valid {
+            self.output[start + 1] |= TS_TRANSPORT_ERROR;
+        }
+    }
+
+    fn push(&mut self, b: u8) {
+        if self.count == 0 {
+            // CRC-8 of the previous packet in place of the sync byte
+            if let Some(packet) = self.pending.take() {
+                let valid = crc8(0, &packet) == b;
+                if !valid {
+                    self.packet_errors += 1;
+                }
+                self.emit(&packet, valid);
+            }
+        } else {
+            self.current.push(b);
+        }
+        self.count = (self.count + 1) % TS_PACKET_SIZE;
+        if self.count == 0 {
+            self.pending = Some(std::mem::take(&mut self.current));
+        }
+    }
+
+    fn lose_sync(&mut self) {
+        self.synced = false;
+        self.count = 0;
+        self.current.clear();
+        self.pending = None;
+    }
+
+    fn deframe(&mut self, bits: &[u8]) {
+        self.output.clear();
+        self.pos = 0;
+
+        let header = pack_bits(&bits[..BB_HEADER_LENGTH_BITS]);
+        if crc8(0, &header[..9]) != header[9] {
+            self.header_errors += 1;
+            self.lose_sync();
+            return;
+        }
+        let ts = header[0] >> 6 == 0x3;
+        let upl = u16::from_be_bytes([header[2], header[3]]) as usize;
+        let dfl = u16::from_be_bytes([header[4], header[5]]) as usize;
+        let syncd = u16::from_be_bytes([header[7], header[8]]);
+        if !ts
+            || upl != TS_PACKET_SIZE * 8
+            || !dfl.is_multiple_of(8)
+            || dfl > self.kbch - BB_HEADER_LENGTH_BITS
+            || (syncd != u16::MAX && (!syncd.is_multiple_of(8) || syncd as usize >= dfl))
+        {
+            warn!("BbDeframer: unsupported BBHEADER {header:02x?}");
+            self.lose_sync();
+            return;
+        }
+
+        let data = pack_bits(&bits[BB_HEADER_LENGTH_BITS..BB_HEADER_LENGTH_BITS + dfl]);
+        if syncd == u16::MAX {
+            if self.synced {
+                data.iter().for_each(|&b| self.push(b));
+            }
+            return;
+        }
+        let start = syncd as usize / 8;
+        if self.synced {
+            data[..start].iter().for_each(|&b| self.push(b));
+        }
+        if !self.synced || self.count != 0 {
+            self.lose_sync();
+            self.synced = true;
+        }
+        data[start..].iter().for_each(|&b| self.push(b));
+    }
+}
+
+#[async_trait]
+impl Kernel for BbDeframer {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<u8>();
+        let errors = (self.header_errors, self.packet_errors);
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if self.pos < self.output.len() {
+                let n = std::cmp::min(self.output.len() - self.pos, o.len() - produced);
+                if n == 0 {
+                    break;
+                }
+                if let Some(start) = self.unchecked {
+                    if start < self.pos + n {
+                        sio.output(0).add_tag(
+                            produced + start - self.pos,
+                            Tag::String(CRC_UNCHECKED_TAG.to_string()),
+                        );
+                        self.unchecked = None;
+                    }
+                }
+                o[produced..produced + n].copy_from_slice(&self.output[self.pos..self.pos + n]);
+                self.pos += n;
+                produced += n;
+            } else if i.len() - consumed >= self.kbch {
+                self.deframe(&i[consumed..consumed + self.kbch]);
+                consumed += self.kbch;
+            } else if sio.input(0).finished() && self.pending.is_some() {
+                // The CRC-8 of the last packet is never sent, so it cannot be checked
+                let packet = self.pending.take().unwrap();
+                self.output.clear();
+                self.pos = 0;
+                self.unchecked = Some(0);
+                self.emit(&packet, true);
+            } else {
+                break;
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        if (self.header_errors, self.packet_errors) != errors {
+            let counts = HashMap::from([
+                ("header".to_string(), Pmt::Usize(self.header_errors)),
+                ("packet".to_string(), Pmt::Usize(self.packet_errors)),
+            ]);
+            mio.post(0, Pmt::MapStrPmt(counts)).await;
+        }
+
+        io.finished = sio.input(0).finished()
+            && i.len() - consumed < self.kbch
+            && self.pos == self.output.len()
+            && self.pending.is_none();
+        Ok(())
+    }
+}
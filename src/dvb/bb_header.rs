@@ -0,0 +1,218 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::log::warn;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::bch_encoder::BchCode;
+use super::config::{CodeRate, Framesize, RolloffFactor};
+
+pub(super) const BB_HEADER_LENGTH_BITS: usize = 80;
+pub(super) const TS_PACKET_SIZE: usize = 188;
+pub(super) const TS_SYNC_BYTE: u8 = 0x47;
+
+const CRC_POLY: u8 = 0xD5;
+
+pub(super) fn crc8(crc: u8, data: &[u8]) -> u8 {
+    data.iter().fold(crc, |crc, &b| {
+        let mut crc = crc ^ b;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ CRC_POLY
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn unpack_bits(bytes: &[u8], bits: &mut [u8]) {
+    for (b, out) in bytes.iter().zip(bits.chunks_exact_mut(8)) {
+        for (n, bit) in out.iter_mut().enumerate() {
+            *bit = (b >> (7 - n)) & 1;
+        }
+    }
+}
+
+/// Maps 188-byte TS packets to BBFRAMEs of `kbch` bits, one per byte.
+///
+/// Every packet must start with the sync byte `0x47`. Input bytes are skipped until one is
+/// found; when a packet does not start with it, the block warns, posts the number of sync
+/// losses on the `sync_losses` message port and skips ahead to the next sync byte.
+pub struct BbHeader {
+    kbch: usize,
+    /// Length of the DATA FIELD in bytes
+    dfl: usize,
+    rolloff: u8,
+    /// Low rolloff factors of DVB-S2X are signalled by alternating RO with `0b11`
+    alternate: bool,
+    frame: usize,
+    /// Position inside the current user packet
+    count: usize,
+    crc: u8,
+    /// Aligned input bytes of the next DATA FIELD
+    data: Vec<u8>,
+    /// Position of the next input byte inside its packet
+    offset: usize,
+    synced: bool,
+    sync_losses: usize,
+}
+
+impl BbHeader {
+    pub fn new(framesize: Framesize, rate: CodeRate, rolloff: RolloffFactor) -> Option<Block> {
+        let kbch = BchCode::dvb(framesize, rate)?.k();
+        rolloff.value()?;
+        let rolloff = rolloff as u8;
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_BB_Header").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_output("sync_losses")
+                .build(),
+            Self {
+                kbch,
+                dfl: (kbch - BB_HEADER_LENGTH_BITS) / 8,
+                rolloff: rolloff & 0x3,
+                alternate: rolloff & 0x4 != 0,
+                frame: 0,
+                count: 0,
+                crc: 0,
+                data: Vec::new(),
+                offset: 0,
+                synced: false,
+                sync_losses: 0,
+            },
+        ))
+    }
+
+    fn header(&mut self, dfl: usize, out: &mut [u8]) {
+        let ro = if self.alternate && self.frame % 2 == 1 {
+            0x3
+        } else {
+            self.rolloff
+        };
+        // TS, single input stream, CCM, no ISSY, no null packet deletion
+        let matype1 = 0xF0 | ro;
+        let upl = (TS_PACKET_SIZE * 8) as u16;
+        let syncd = match self.count {
+            0 => 0,
+            c if TS_PACKET_SIZE - c < dfl => ((TS_PACKET_SIZE - c) * 8) as u16,
+            _ => u16::MAX,
+        };
+        let dfl = (dfl * 8) as u16;
+        let mut header = [
+            matype1,
+            0,
+            (upl >> 8) as u8,
+            upl as u8,
+            (dfl >> 8) as u8,
+            dfl as u8,
+            TS_SYNC_BYTE,
+            (syncd >> 8) as u8,
+            syncd as u8,
+            0,
+        ];
+        header[9] = crc8(0, &header[..9]);
+        unpack_bits(&header, out);
+        self.frame += 1;
+    }
+
+    /// Writes user packets to `out`, putting the CRC-8 of each packet in place of the sync
+    /// byte of the next one.
+    fn data_field(&mut self, data: &[u8], out: &mut [u8]) {
+        for (&b, bits) in data.iter().zip(out.chunks_exact_mut(8)) {
+            let b = if self.count == 0 {
+                std::mem::replace(&mut self.crc, 0)
+            } else {
+                self.crc = crc8(self.crc, &[b]);
+                b
+            };
+            self.count = (self.count + 1) % TS_PACKET_SIZE;
+            unpack_bits(&[b], bits);
+        }
+    }
+
+    fn frame(&mut self, data: &[u8], out: &mut [u8]) {
+        let (header, rest) = out.split_at_mut(BB_HEADER_LENGTH_BITS);
+        self.header(data.len(), header);
+        let (field, padding) = rest.split_at_mut(data.len() * 8);
+        self.data_field(data, field);
+        padding.fill(0);
+    }
+}
+
+#[async_trait]
+impl Kernel for BbHeader {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<u8>();
+        let n = self.kbch;
+        let losses = self.sync_losses;
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if self.data.len() == self.dfl {
+                if o.len() - produced < n {
+                    break;
+                }
+                let data = std::mem::take(&mut self.data);
+                self.frame(&data, &mut o[produced..produced + n]);
+                self.data = data;
+                self.data.clear();
+                produced += n;
+            } else if consumed < i.len() {
+                if self.offset == 0 {
+                    if i[consumed] != TS_SYNC_BYTE {
+                        if self.synced {
+                            warn!("BbHeader: lost TS sync");
+                            self.synced = false;
+                            self.sync_losses += 1;
+                        }
+                        consumed += 1;
+                        continue;
+                    }
+                    self.synced = true;
+                }
+                let len = (self.dfl - self.data.len())
+                    .min(TS_PACKET_SIZE - self.offset)
+                    .min(i.len() - consumed);
+                self.data.extend_from_slice(&i[consumed..consumed + len]);
+                consumed += len;
+                self.offset = (self.offset + len) % TS_PACKET_SIZE;
+            } else {
+                break;
+            }
+        }
+
+        if sio.input(0).finished() && consumed == i.len() && o.len() - produced >= n {
+            // Flush the remaining packets with a shortened DATA FIELD
+            if !self.data.is_empty() {
+                let data = std::mem::take(&mut self.data);
+                self.frame(&data, &mut o[produced..produced + n]);
+                produced += n;
+            }
+            io.finished = true;
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        if self.sync_losses != losses {
+            mio.post(0, Pmt::Usize(self.sync_losses)).await;
+        }
+        Ok(())
+    }
+}
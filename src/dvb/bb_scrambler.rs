@@ -0,0 +1,71 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::bch_encoder::BchCode;
+use super::config::{CodeRate, Framesize};
+
+/// PRBS sequence `1 + X^14 + X^15` with the initialization sequence `100101010000000`
+pub(super) fn bb_randomise(len: usize) -> Vec<u8> {
+    let mut sr = 0x4A80u16;
+    (0..len)
+        .map(|_| {
+            let b = ((sr ^ (sr >> 1)) & 1) as u8;
+            sr >>= 1;
+            if b != 0 {
+                sr |= 0x4000;
+            }
+            b
+        })
+        .collect()
+}
+
+pub struct BbScrambler(Vec<u8>);
+
+impl BbScrambler {
+    pub fn new(framesize: Framesize, rate: CodeRate) -> Option<Block> {
+        let kbch = BchCode::dvb(framesize, rate)?.k();
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_BB_Scrambler").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self(bb_randomise(kbch)),
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for BbScrambler {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<u8>();
+        let k = self.0.len();
+
+        let m = std::cmp::min(i.len(), o.len()) / k;
+        if m > 0 {
+            for (v, r) in i.chunks_exact(k).zip(o.chunks_exact_mut(k)) {
+                for ((r, v), p) in r.iter_mut().zip(v).zip(&self.0) {
+                    *r = v ^ p;
+                }
+            }
+
+            sio.input(0).consume(k * m);
+            sio.output(0).produce(k * m);
+        }
+
+        io.finished = sio.input(0).finished() && i.len() - k * m < k;
+        Ok(())
+    }
+}
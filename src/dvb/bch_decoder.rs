@@ -0,0 +1,235 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::bch_encoder::BchCode;
+use super::config::{CodeRate, Framesize};
+
+/// GF(2^m) with exponential and logarithm tables
+struct Gf {
+    exp: Vec<u16>,
+    log: Vec<u16>,
+    /// Multiplicative order `2^m - 1`
+    order: usize,
+}
+
+impl Gf {
+    fn new(m: usize, poly: u32) -> Self {
+        let order = (1 << m) - 1;
+        let mut exp = vec![0; 2 * order];
+        let mut log = vec![0; order + 1];
+        let mut x = 1u32;
+        for i in 0..order {
+            exp[i] = x as u16;
+            exp[i + order] = x as u16;
+            log[x as usize] = i as u16;
+            x <<= 1;
+            if x & (1 << m) != 0 {
+                x ^= poly;
+            }
+        }
+        Self { exp, log, order }
+    }
+
+    fn mul(&self, a: u16, b: u16) -> u16 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            self.exp[self.log[a as usize] as usize + self.log[b as usize] as usize]
+        }
+    }
+
+    fn div(&self, a: u16, b: u16) -> u16 {
+        if a == 0 {
+            0
+        } else {
+            self.exp[self.log[a as usize] as usize + self.order - self.log[b as usize] as usize]
+        }
+    }
+
+    /// `α^i`
+    fn pow(&self, i: usize) -> u16 {
+        self.exp[i % self.order]
+    }
+}
+
+/// Bit `i` of a codeword is the coefficient of `x^(n-1-i)`.
+pub(super) struct BchDecoderCode {
+    n: usize,
+    k: usize,
+    t: usize,
+    gf: Gf,
+}
+
+impl BchDecoderCode {
+    pub fn new(code: BchCode) -> Self {
+        let gf = match code {
+            BchCode::Normal12 { .. } | BchCode::Normal10 { .. } | BchCode::Normal8 { .. } => {
+                Gf::new(16, 0x1002D)
+            }
+            BchCode::Short12 { .. } => Gf::new(14, 0x402B),
+            BchCode::Medium12 { .. } => Gf::new(15, 0x802D),
+        };
+        Self {
+            n: code.n(),
+            k: code.k(),
+            t: code.t(),
+            gf,
+        }
+    }
+
+    /// Syndromes `S_1` to `S_2t`; `None` if all of them are zero.
+    fn syndromes(&self, word: &[u8]) -> Option<Vec<u16>> {
+        let mut s = vec![0u16; 2 * self.t];
+        for j in (1..=2 * self.t).step_by(2) {
+            s[j - 1] = word
+                .iter()
+                .enumerate()
+                .filter(|(_, &b)| b != 0)
+                .fold(0, |acc, (i, _)| acc ^ self.gf.pow(j * (self.n - 1 - i)));
+        }
+        for j in (2..=2 * self.t).step_by(2) {
+            s[j - 1] = self.gf.mul(s[j / 2 - 1], s[j / 2 - 1]);
+        }
+        s.iter().any(|&x| x != 0).then_some(s)
+    }
+
+    /// Berlekamp-Massey; returns the error locator polynomial, lowest degree first.
+    fn error_locator(&self, s: &[u16]) -> Vec<u16> {
+        let gf = &self.gf;
+        let mut c = vec![0u16; s.len() + 1];
+        let mut b = vec![0u16; s.len() + 1];
+        c[0] = 1;
+        b[0] = 1;
+        let mut l = 0;
+        let mut m = 1;
+        let mut last = 1u16;
+        for r in 0..s.len() {
+            let d = (1..=l).fold(s[r], |d, i| d ^ gf.mul(c[i], s[r - i]));
+            if d == 0 {
+                m += 1;
+                continue;
+            }
+            let f = gf.div(d, last);
+            let prev = c.clone();
+            for i in m..c.len() {
+                c[i] ^= gf.mul(f, b[i - m]);
+            }
+            if 2 * l <= r {
+                l = r + 1 - l;
+                b = prev;
+                last = d;
+                m = 1;
+            } else {
+                m += 1;
+            }
+        }
+        c.truncate(l + 1);
+        c
+    }
+
+    /// Corrects `word` in place and returns the number of corrected bits, or `None` if the
+    /// errors are not correctable.
+    pub fn decode(&self, word: &mut [u8]) -> Option<usize> {
+        let s = match self.syndromes(word) {
+            Some(s) => s,
+            None => return Some(0),
+        };
+        let lambda = self.error_locator(&s);
+        let errors = lambda.len() - 1;
+        if errors > self.t {
+            return None;
+        }
+
+        // Chien search over the codeword positions; an error at x^p is a root at α^-p.
+        let gf = &self.gf;
+        let mut positions = Vec::with_capacity(errors);
+        for p in 0..self.n {
+            let inv = gf.order - p % gf.order;
+            let v = lambda
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, &c)| acc ^ gf.mul(c, gf.pow(inv * i)));
+            if v == 0 {
+                positions.push(self.n - 1 - p);
+                if positions.len() == errors {
+                    break;
+                }
+            }
+        }
+        if positions.len() != errors {
+            return None;
+        }
+        for i in positions {
+            word[i] ^= 1;
+        }
+        Some(errors)
+    }
+}
+
+pub struct BchDecoder {
+    code: BchDecoderCode,
+    uncorrectable: usize,
+}
+
+impl BchDecoder {
+    pub fn new(framesize: Framesize, rate: CodeRate) -> Option<Block> {
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_BCH_Decoder").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_output("uncorrectable")
+                .build(),
+            Self {
+                code: BchDecoderCode::new(BchCode::dvb(framesize, rate)?),
+                uncorrectable: 0,
+            },
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for BchDecoder {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<u8>();
+        let n = self.code.n;
+        let k = self.code.k;
+
+        let m = std::cmp::min(i.len() / n, o.len() / k);
+        let mut failed = 0;
+        if m > 0 {
+            let mut word = vec![0u8; n];
+            for (v, r) in i.chunks_exact(n).zip(o.chunks_exact_mut(k)) {
+                word.copy_from_slice(v);
+                if self.code.decode(&mut word).is_none() {
+                    failed += 1;
+                }
+                r.copy_from_slice(&word[..k]);
+            }
+
+            sio.input(0).consume(n * m);
+            sio.output(0).produce(k * m);
+        }
+
+        if failed > 0 {
+            self.uncorrectable += failed;
+            mio.post(0, Pmt::Usize(self.uncorrectable)).await;
+        }
+
+        io.finished = sio.input(0).finished() && i.len() - n * m < n;
+        Ok(())
+    }
+}
@@ -35,13 +35,13 @@ const NUM_PARITY_N10: usize = 160;
 const NUM_PARITY_N8: usize = 128;
 const NUM_PARITY_S12: usize = 168;
 const NUM_PARITY_M12: usize = 180;
-const POLY_SIZE_N12: usize = NUM_PARITY_N12 / 32 + if NUM_PARITY_N12 % 32 != 0 { 1 } else { 0 };
-const POLY_SIZE_N10: usize = NUM_PARITY_N10 / 32 + if NUM_PARITY_N10 % 32 != 0 { 1 } else { 0 };
-const POLY_SIZE_N8: usize = NUM_PARITY_N8 / 32 + if NUM_PARITY_N8 % 32 != 0 { 1 } else { 0 };
-const POLY_SIZE_S12: usize = NUM_PARITY_S12 / 32 + if NUM_PARITY_S12 % 32 != 0 { 1 } else { 0 };
-const POLY_SIZE_M12: usize = NUM_PARITY_M12 / 32 + if NUM_PARITY_M12 % 32 != 0 { 1 } else { 0 };
+const POLY_SIZE_N12: usize = NUM_PARITY_N12.div_ceil(32);
+const POLY_SIZE_N10: usize = NUM_PARITY_N10.div_ceil(32);
+const POLY_SIZE_N8: usize = NUM_PARITY_N8.div_ceil(32);
+const POLY_SIZE_S12: usize = NUM_PARITY_S12.div_ceil(32);
+const POLY_SIZE_M12: usize = NUM_PARITY_M12.div_ceil(32);
 
-enum BchCode {
+pub(super) enum BchCode {
     Normal12 {
         k: usize,
         n: usize,
@@ -69,9 +69,15 @@ enum BchCode {
     },
 }
 
+/// Packs the polynomial right-aligned, so that the last coefficient ends up in the LSB of the last
+/// word, where the shift register taps its feedback.
+///
 /// ### TODO: optimize bit operations
 fn poly_pack(poly: Vec<u8>) -> Vec<u32> {
-    poly.chunks(32)
+    let mut padded = vec![0; poly.len().next_multiple_of(32) - poly.len()];
+    padded.extend(poly);
+    padded
+        .chunks(32)
         .map(|chunk| {
             let mut out = 0;
             let mut bit = 0x80000000;
@@ -87,6 +93,35 @@ fn poly_pack(poly: Vec<u8>) -> Vec<u32> {
 }
 
 impl BchCode {
+    pub fn k(&self) -> usize {
+        match *self {
+            Self::Normal12 { k, .. }
+            | Self::Normal10 { k, .. }
+            | Self::Normal8 { k, .. }
+            | Self::Short12 { k, .. }
+            | Self::Medium12 { k, .. } => k,
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        match *self {
+            Self::Normal12 { n, .. }
+            | Self::Normal10 { n, .. }
+            | Self::Normal8 { n, .. }
+            | Self::Short12 { n, .. }
+            | Self::Medium12 { n, .. } => n,
+        }
+    }
+
+    /// Number of correctable errors
+    pub fn t(&self) -> usize {
+        match self {
+            Self::Normal12 { .. } | Self::Short12 { .. } | Self::Medium12 { .. } => 12,
+            Self::Normal10 { .. } => 10,
+            Self::Normal8 { .. } => 8,
+        }
+    }
+
     fn new_n_12(n: usize) -> Self {
         let mut poly = poly_mult(
             &[1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
@@ -309,10 +344,9 @@ impl<const SIZE: usize> ShiftReg<SIZE> {
     }
 
     pub fn xor(&mut self, other: &[u32]) {
-        // self.0.iter_mut().zip(other.iter()).for_each(|(s, p)| *s ^= *p);
         if other.len() >= SIZE {
-            for i in 0..SIZE {
-                self.0[i] ^= other[i];
+            for (s, p) in self.0.iter_mut().zip(other.iter()) {
+                *s ^= *p;
             }
         }
     }
@@ -0,0 +1,162 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::log::warn;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::bit_interleaver::Interleaving;
+use super::config::{CodeRate, Constellation, Framesize, Modcod, PLS_TAG};
+
+fn interleaving(pls: usize) -> Option<Interleaving> {
+    let modcod = Modcod::try_from(u8::try_from(pls).ok()?).ok()?;
+    Interleaving::dvb(
+        modcod.framesize()?,
+        modcod.code_rate()?,
+        modcod.constellation()?,
+    )
+}
+
+/// Inverse of the [`BitInterleaver`](super::BitInterleaver) on LLRs.
+///
+/// The MODCOD given to [`BitDeinterleaver::new`] applies until the first PLS tag. A PLS tag at
+/// the first LLR of a frame switches to the interleaving of its MODCOD and is forwarded to the
+/// first LLR of the deinterleaved frame. Frames with an unsupported PLS code are dropped up to
+/// the next tag, as are the LLRs of an incomplete frame when the next tag arrives.
+pub struct BitDeinterleaver {
+    interleaving: Interleaving,
+    /// Discarding the LLRs of a frame with an unsupported PLS code
+    dropping: bool,
+    pls: Option<usize>,
+    input: Vec<f32>,
+    output: Vec<f32>,
+    /// Number of `output` LLRs already produced
+    pos: usize,
+}
+
+impl BitDeinterleaver {
+    pub fn new(
+        framesize: Framesize,
+        rate: CodeRate,
+        constellation: Constellation,
+    ) -> Option<Block> {
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Bit_Deinterleaver").build(),
+            StreamIoBuilder::new()
+                .add_input::<f32>("in")
+                .add_output::<f32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                interleaving: Interleaving::dvb(framesize, rate, constellation)?,
+                dropping: false,
+                pls: None,
+                input: Vec::new(),
+                output: Vec::new(),
+                pos: 0,
+            },
+        ))
+    }
+
+    fn deinterleave(&mut self) {
+        let il = &self.interleaving;
+        self.output.resize(il.n, 0.0);
+        for (s, llr) in self.input.chunks_exact(il.bits).enumerate() {
+            for (b, &l) in llr.iter().enumerate() {
+                self.output[il.index(s, b)] = l;
+            }
+        }
+        self.input.clear();
+        self.pos = 0;
+    }
+}
+
+#[async_trait]
+impl Kernel for BitDeinterleaver {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<f32>();
+        let o = sio.output(0).slice::<f32>();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if self.pos < self.output.len() {
+                let n = std::cmp::min(self.output.len() - self.pos, o.len() - produced);
+                if n == 0 {
+                    break;
+                }
+                if self.pos == 0 {
+                    if let Some(pls) = self.pls.take() {
+                        sio.output(0)
+                            .add_tag(produced, Tag::NamedUsize(PLS_TAG.to_string(), pls));
+                    }
+                }
+                o[produced..produced + n].copy_from_slice(&self.output[self.pos..self.pos + n]);
+                self.pos += n;
+                produced += n;
+            } else if self.input.len() == self.interleaving.n {
+                self.deinterleave();
+            } else if consumed < i.len() {
+                let tags = sio.input(0).tags();
+                let pls = tags.iter().find_map(|t| match &t.tag {
+                    Tag::NamedUsize(name, pls) if t.index == consumed && name == PLS_TAG => {
+                        Some(*pls)
+                    }
+                    _ => None,
+                });
+                let next = tags
+                    .iter()
+                    .filter(|t| {
+                        t.index > consumed
+                            && matches!(&t.tag, Tag::NamedUsize(name, _) if name == PLS_TAG)
+                    })
+                    .map(|t| t.index)
+                    .min()
+                    .unwrap_or(i.len())
+                    .min(i.len());
+                if let Some(pls) = pls {
+                    if !self.input.is_empty() {
+                        warn!("BitDeinterleaver: incomplete frame");
+                        self.input.clear();
+                    }
+                    match interleaving(pls) {
+                        Some(il) => {
+                            self.interleaving = il;
+                            self.dropping = false;
+                            self.pls = Some(pls);
+                        }
+                        None => {
+                            warn!("BitDeinterleaver: dropping frame with PLS code {pls}");
+                            self.dropping = true;
+                        }
+                    }
+                }
+                if self.dropping {
+                    consumed = next;
+                    continue;
+                }
+                let n = std::cmp::min(self.interleaving.n - self.input.len(), next - consumed);
+                self.input.extend_from_slice(&i[consumed..consumed + n]);
+                consumed += n;
+            } else {
+                break;
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished = sio.input(0).finished()
+            && consumed == i.len()
+            && self.pos == self.output.len()
+            && self.input.len() < self.interleaving.n;
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::bch_encoder::BchCode;
+use super::config::{CodeRate, Constellation, Framesize};
+
+/// Interleaving of a FECFRAME into groups of `bits` bits per symbol.
+///
+/// The FECFRAME is written column-wise into `bits` columns and read row-wise; `columns` is the
+/// order in which the columns are read out, starting with the MSB of the symbol.
+/// `None` means that consecutive bits are grouped without interleaving.
+pub(super) struct Interleaving {
+    pub n: usize,
+    pub bits: usize,
+    pub columns: Option<&'static [usize]>,
+}
+
+impl Interleaving {
+    /// Only the DVB-S2 code rates of 8PSK, 16APSK and 32APSK are interleaved; see
+    /// [`BitInterleaver`] for the MODCODs without column order.
+    pub fn dvb(framesize: Framesize, rate: CodeRate, constellation: Constellation) -> Option<Self> {
+        BchCode::dvb(framesize, rate)?;
+        let columns: Option<&'static [usize]> = match constellation {
+            Constellation::ModBpsk | Constellation::ModBpskSf2 | Constellation::ModQpsk => None,
+            Constellation::Mod8psk => match rate {
+                CodeRate::C3_5 => Some(&[2, 1, 0]),
+                CodeRate::C2_3
+                | CodeRate::C3_4
+                | CodeRate::C5_6
+                | CodeRate::C8_9
+                | CodeRate::C9_10 => Some(&[0, 1, 2]),
+                _ => return None,
+            },
+            Constellation::Mod16apsk => match rate {
+                CodeRate::C2_3
+                | CodeRate::C3_4
+                | CodeRate::C4_5
+                | CodeRate::C5_6
+                | CodeRate::C8_9
+                | CodeRate::C9_10 => Some(&[0, 1, 2, 3]),
+                _ => return None,
+            },
+            Constellation::Mod32apsk => match rate {
+                CodeRate::C3_4
+                | CodeRate::C4_5
+                | CodeRate::C5_6
+                | CodeRate::C8_9
+                | CodeRate::C9_10 => Some(&[0, 1, 2, 3, 4]),
+                _ => return None,
+            },
+            Constellation::Mod8apsk
+            | Constellation::Mod8_8apsk
+            | Constellation::Mod4_12_16apsk
+            | Constellation::Mod4_8_4_16apsk
+            | Constellation::Mod64apsk
+            | Constellation::Mod8_16_20_20apsk
+            | Constellation::Mod4_12_20_28apsk
+            | Constellation::Mod128apsk
+            | Constellation::Mod256apsk
+            | Constellation::ModOther => return None,
+        };
+        Some(Self {
+            n: framesize.frame_size(),
+            bits: constellation.bits_per_symbol(),
+            columns,
+        })
+    }
+
+    /// Index into the FECFRAME of bit `b` (0 is the MSB) of symbol `s`.
+    pub fn index(&self, s: usize, b: usize) -> usize {
+        match self.columns {
+            Some(columns) => columns[b] * (self.n / self.bits) + s,
+            None => s * self.bits + b,
+        }
+    }
+}
+
+/// Column-twist bit interleaver of DVB-S2 (EN 302 307-1, clause 5.3.3).
+///
+/// Covers the DVB-S2 MODCODs and the π/2-BPSK, BPSK-SF2 and QPSK MODCODs of DVB-S2X, which are
+/// not interleaved. The column orders of the other DVB-S2X MODCODs (EN 302 307-2, Table 20) are
+/// not implemented, and [`BitInterleaver::new`] returns `None` for them, as does the
+/// [`BitDeinterleaver`](super::BitDeinterleaver).
+pub struct BitInterleaver(Interleaving);
+
+impl BitInterleaver {
+    pub fn new(
+        framesize: Framesize,
+        rate: CodeRate,
+        constellation: Constellation,
+    ) -> Option<Block> {
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Bit_Interleaver").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self(Interleaving::dvb(framesize, rate, constellation)?),
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for BitInterleaver {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<u8>();
+        let n = self.0.n;
+        let s = n / self.0.bits;
+
+        let m = std::cmp::min(i.len() / n, o.len() / s);
+        if m > 0 {
+            for (v, r) in i.chunks_exact(n).zip(o.chunks_exact_mut(s)) {
+                for (j, sym) in r.iter_mut().enumerate() {
+                    *sym = (0..self.0.bits).fold(0, |acc, b| (acc << 1) | v[self.0.index(j, b)]);
+                }
+            }
+
+            sio.input(0).consume(n * m);
+            sio.output(0).produce(s * m);
+        }
+
+        io.finished = sio.input(0).finished() && i.len() - n * m < n;
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Framesize {
     Normal,
     Short,
@@ -21,7 +21,7 @@ impl Framesize {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CodeRate {
     C1_4,
     C1_3,
@@ -75,7 +75,65 @@ pub enum CodeRate {
     COther,
 }
 
-#[derive(Clone, Copy)]
+impl CodeRate {
+    /// Nominal code rate
+    pub fn value(&self) -> Option<f32> {
+        match self {
+            CodeRate::C1_4 => Some(1.0 / 4.0),
+            CodeRate::C1_3 => Some(1.0 / 3.0),
+            CodeRate::C2_5 => Some(2.0 / 5.0),
+            CodeRate::C1_2 => Some(1.0 / 2.0),
+            CodeRate::C3_5 => Some(3.0 / 5.0),
+            CodeRate::C2_3 => Some(2.0 / 3.0),
+            CodeRate::C3_4 => Some(3.0 / 4.0),
+            CodeRate::C4_5 => Some(4.0 / 5.0),
+            CodeRate::C5_6 => Some(5.0 / 6.0),
+            CodeRate::C8_9 => Some(8.0 / 9.0),
+            CodeRate::C9_10 => Some(9.0 / 10.0),
+            CodeRate::C13_45 => Some(13.0 / 45.0),
+            CodeRate::C9_20 => Some(9.0 / 20.0),
+            CodeRate::C90_180 => Some(90.0 / 180.0),
+            CodeRate::C96_180 => Some(96.0 / 180.0),
+            CodeRate::C11_20 => Some(11.0 / 20.0),
+            CodeRate::C100_180 => Some(100.0 / 180.0),
+            CodeRate::C104_180 => Some(104.0 / 180.0),
+            CodeRate::C26_45 => Some(26.0 / 45.0),
+            CodeRate::C18_30 => Some(18.0 / 30.0),
+            CodeRate::C28_45 => Some(28.0 / 45.0),
+            CodeRate::C23_36 => Some(23.0 / 36.0),
+            CodeRate::C116_180 => Some(116.0 / 180.0),
+            CodeRate::C20_30 => Some(20.0 / 30.0),
+            CodeRate::C124_180 => Some(124.0 / 180.0),
+            CodeRate::C25_36 => Some(25.0 / 36.0),
+            CodeRate::C128_180 => Some(128.0 / 180.0),
+            CodeRate::C13_18 => Some(13.0 / 18.0),
+            CodeRate::C132_180 => Some(132.0 / 180.0),
+            CodeRate::C22_30 => Some(22.0 / 30.0),
+            CodeRate::C135_180 => Some(135.0 / 180.0),
+            CodeRate::C140_180 => Some(140.0 / 180.0),
+            CodeRate::C7_9 => Some(7.0 / 9.0),
+            CodeRate::C154_180 => Some(154.0 / 180.0),
+            CodeRate::C11_45 => Some(11.0 / 45.0),
+            CodeRate::C4_15 => Some(4.0 / 15.0),
+            CodeRate::C14_45 => Some(14.0 / 45.0),
+            CodeRate::C7_15 => Some(7.0 / 15.0),
+            CodeRate::C8_15 => Some(8.0 / 15.0),
+            CodeRate::C32_45 => Some(32.0 / 45.0),
+            CodeRate::C2_9Vlsnr => Some(2.0 / 9.0),
+            CodeRate::C1_5Medium => Some(1.0 / 5.0),
+            CodeRate::C11_45Medium => Some(11.0 / 45.0),
+            CodeRate::C1_3Medium => Some(1.0 / 3.0),
+            CodeRate::C1_5VlsnrSf2 => Some(1.0 / 5.0),
+            CodeRate::C11_45VlsnrSf2 => Some(11.0 / 45.0),
+            CodeRate::C1_5Vlsnr => Some(1.0 / 5.0),
+            CodeRate::C4_15Vlsnr => Some(4.0 / 15.0),
+            CodeRate::C1_3Vlsnr => Some(1.0 / 3.0),
+            CodeRate::COther => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Constellation {
     ModQpsk,
     Mod8psk,
@@ -85,11 +143,36 @@ pub enum Constellation {
     Mod32apsk,
     Mod4_12_16apsk,
     Mod4_8_4_16apsk,
+    Mod64apsk,
+    Mod8_16_20_20apsk,
+    Mod4_12_20_28apsk,
+    Mod128apsk,
+    Mod256apsk,
     ModBpsk,
     ModBpskSf2,
     ModOther,
 }
 
+impl Constellation {
+    pub fn bits_per_symbol(&self) -> usize {
+        match self {
+            Constellation::ModBpsk | Constellation::ModBpskSf2 => 1,
+            Constellation::ModQpsk => 2,
+            Constellation::Mod8psk | Constellation::Mod8apsk => 3,
+            Constellation::Mod16apsk | Constellation::Mod8_8apsk => 4,
+            Constellation::Mod32apsk
+            | Constellation::Mod4_12_16apsk
+            | Constellation::Mod4_8_4_16apsk => 5,
+            Constellation::Mod64apsk
+            | Constellation::Mod8_16_20_20apsk
+            | Constellation::Mod4_12_20_28apsk => 6,
+            Constellation::Mod128apsk => 7,
+            Constellation::Mod256apsk => 8,
+            Constellation::ModOther => 0,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 #[repr(u8)]
 pub enum RolloffFactor {
@@ -102,7 +185,21 @@ pub enum RolloffFactor {
     Ro0_05,
 }
 
-#[derive(Clone, Copy)]
+impl RolloffFactor {
+    pub fn value(&self) -> Option<f32> {
+        match self {
+            RolloffFactor::Ro0_35 => Some(0.35),
+            RolloffFactor::Ro0_25 => Some(0.25),
+            RolloffFactor::Ro0_20 => Some(0.20),
+            RolloffFactor::RoReserved => None,
+            RolloffFactor::Ro0_15 => Some(0.15),
+            RolloffFactor::Ro0_10 => Some(0.10),
+            RolloffFactor::Ro0_05 => Some(0.05),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum Modcod {
     McDummy,
@@ -226,6 +323,227 @@ pub enum Modcod {
     Mc32apsk32_45S,
 }
 
+/// DVB-S2 MODCODs in PLS order with their constellation and code rate, as pairs of the normal and
+/// the short FECFRAME variant
+#[rustfmt::skip]
+const DVB_S2_MODCODS: [(Modcod, Modcod, Constellation, CodeRate); 28] = [
+    (Modcod::McQpsk1_4, Modcod::McQpsk1_4S, Constellation::ModQpsk, CodeRate::C1_4),
+    (Modcod::McQpsk1_3, Modcod::McQpsk1_3S, Constellation::ModQpsk, CodeRate::C1_3),
+    (Modcod::McQpsk2_5, Modcod::McQpsk2_5S, Constellation::ModQpsk, CodeRate::C2_5),
+    (Modcod::McQpsk1_2, Modcod::McQpsk1_2S, Constellation::ModQpsk, CodeRate::C1_2),
+    (Modcod::McQpsk3_5, Modcod::McQpsk3_5S, Constellation::ModQpsk, CodeRate::C3_5),
+    (Modcod::McQpsk2_3, Modcod::McQpsk2_3S, Constellation::ModQpsk, CodeRate::C2_3),
+    (Modcod::McQpsk3_4, Modcod::McQpsk3_4S, Constellation::ModQpsk, CodeRate::C3_4),
+    (Modcod::McQpsk4_5, Modcod::McQpsk4_5S, Constellation::ModQpsk, CodeRate::C4_5),
+    (Modcod::McQpsk5_6, Modcod::McQpsk5_6S, Constellation::ModQpsk, CodeRate::C5_6),
+    (Modcod::McQpsk8_9, Modcod::McQpsk8_9S, Constellation::ModQpsk, CodeRate::C8_9),
+    (Modcod::McQpsk9_10, Modcod::McQpsk9_10S, Constellation::ModQpsk, CodeRate::C9_10),
+    (Modcod::Mc8psk3_5, Modcod::Mc8psk3_5S, Constellation::Mod8psk, CodeRate::C3_5),
+    (Modcod::Mc8psk2_3, Modcod::Mc8psk2_3S, Constellation::Mod8psk, CodeRate::C2_3),
+    (Modcod::Mc8psk3_4, Modcod::Mc8psk3_4S, Constellation::Mod8psk, CodeRate::C3_4),
+    (Modcod::Mc8psk5_6, Modcod::Mc8psk5_6S, Constellation::Mod8psk, CodeRate::C5_6),
+    (Modcod::Mc8psk8_9, Modcod::Mc8psk8_9S, Constellation::Mod8psk, CodeRate::C8_9),
+    (Modcod::Mc8psk9_10, Modcod::Mc8psk9_10S, Constellation::Mod8psk, CodeRate::C9_10),
+    (Modcod::Mc16apsk2_3, Modcod::Mc16apsk2_3S, Constellation::Mod16apsk, CodeRate::C2_3),
+    (Modcod::Mc16apsk3_4, Modcod::Mc16apsk3_4S, Constellation::Mod16apsk, CodeRate::C3_4),
+    (Modcod::Mc16apsk4_5, Modcod::Mc16apsk4_5S, Constellation::Mod16apsk, CodeRate::C4_5),
+    (Modcod::Mc16apsk5_6, Modcod::Mc16apsk5_6S, Constellation::Mod16apsk, CodeRate::C5_6),
+    (Modcod::Mc16apsk8_9, Modcod::Mc16apsk8_9S, Constellation::Mod16apsk, CodeRate::C8_9),
+    (Modcod::Mc16apsk9_10, Modcod::Mc16apsk9_10S, Constellation::Mod16apsk, CodeRate::C9_10),
+    (Modcod::Mc32apsk3_4, Modcod::Mc32apsk3_4S, Constellation::Mod32apsk, CodeRate::C3_4),
+    (Modcod::Mc32apsk4_5, Modcod::Mc32apsk4_5S, Constellation::Mod32apsk, CodeRate::C4_5),
+    (Modcod::Mc32apsk5_6, Modcod::Mc32apsk5_6S, Constellation::Mod32apsk, CodeRate::C5_6),
+    (Modcod::Mc32apsk8_9, Modcod::Mc32apsk8_9S, Constellation::Mod32apsk, CodeRate::C8_9),
+    (Modcod::Mc32apsk9_10, Modcod::Mc32apsk9_10S, Constellation::Mod32apsk, CodeRate::C9_10),
+];
+
+/// DVB-S2X MODCODs (EN 302 307-2, Table 17a) with their FECFRAME size, constellation and code rate
+#[rustfmt::skip]
+const DVB_S2X_MODCODS: [(Modcod, Framesize, Constellation, CodeRate); 64] = [
+    (Modcod::McQpsk13_45, Framesize::Normal, Constellation::ModQpsk, CodeRate::C13_45),
+    (Modcod::McQpsk9_20, Framesize::Normal, Constellation::ModQpsk, CodeRate::C9_20),
+    (Modcod::McQpsk11_20, Framesize::Normal, Constellation::ModQpsk, CodeRate::C11_20),
+    (Modcod::Mc8apsk5_9L, Framesize::Normal, Constellation::Mod8apsk, CodeRate::C100_180),
+    (Modcod::Mc8apsk26_45L, Framesize::Normal, Constellation::Mod8apsk, CodeRate::C104_180),
+    (Modcod::Mc8psk23_36, Framesize::Normal, Constellation::Mod8psk, CodeRate::C23_36),
+    (Modcod::Mc8psk25_36, Framesize::Normal, Constellation::Mod8psk, CodeRate::C25_36),
+    (Modcod::Mc8psk13_18, Framesize::Normal, Constellation::Mod8psk, CodeRate::C13_18),
+    (Modcod::Mc16apsk1_2L, Framesize::Normal, Constellation::Mod8_8apsk, CodeRate::C90_180),
+    (Modcod::Mc16apsk8_15L, Framesize::Normal, Constellation::Mod8_8apsk, CodeRate::C96_180),
+    (Modcod::Mc16apsk5_9L, Framesize::Normal, Constellation::Mod8_8apsk, CodeRate::C100_180),
+    (Modcod::Mc16apsk26_45, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C26_45),
+    (Modcod::Mc16apsk3_5, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C3_5),
+    (Modcod::Mc16apsk3_5L, Framesize::Normal, Constellation::Mod8_8apsk, CodeRate::C18_30),
+    (Modcod::Mc16apsk28_45, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C28_45),
+    (Modcod::Mc16apsk23_36, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C23_36),
+    (Modcod::Mc16apsk2_3L, Framesize::Normal, Constellation::Mod8_8apsk, CodeRate::C20_30),
+    (Modcod::Mc16apsk25_36, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C25_36),
+    (Modcod::Mc16apsk13_18, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C13_18),
+    (Modcod::Mc16apsk7_9, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C140_180),
+    (Modcod::Mc16apsk77_90, Framesize::Normal, Constellation::Mod16apsk, CodeRate::C154_180),
+    (Modcod::Mc32apsk2_3L, Framesize::Normal, Constellation::Mod4_12_16apsk, CodeRate::C2_3),
+    (Modcod::Mc32apsk32_45, Framesize::Normal, Constellation::Mod4_8_4_16apsk, CodeRate::C128_180),
+    (Modcod::Mc32apsk11_15, Framesize::Normal, Constellation::Mod4_8_4_16apsk, CodeRate::C132_180),
+    (Modcod::Mc32apsk7_9, Framesize::Normal, Constellation::Mod4_8_4_16apsk, CodeRate::C140_180),
+    (Modcod::Mc64apsk32_45L, Framesize::Normal, Constellation::Mod64apsk, CodeRate::C128_180),
+    (Modcod::Mc64apsk11_15, Framesize::Normal, Constellation::Mod4_12_20_28apsk, CodeRate::C132_180),
+    (Modcod::Mc64apsk7_9, Framesize::Normal, Constellation::Mod8_16_20_20apsk, CodeRate::C7_9),
+    (Modcod::Mc64apsk4_5, Framesize::Normal, Constellation::Mod8_16_20_20apsk, CodeRate::C4_5),
+    (Modcod::Mc64apsk5_6, Framesize::Normal, Constellation::Mod8_16_20_20apsk, CodeRate::C5_6),
+    (Modcod::Mc128apsk3_4, Framesize::Normal, Constellation::Mod128apsk, CodeRate::C135_180),
+    (Modcod::Mc128apsk7_9, Framesize::Normal, Constellation::Mod128apsk, CodeRate::C140_180),
+    (Modcod::Mc256apsk29_45L, Framesize::Normal, Constellation::Mod256apsk, CodeRate::C116_180),
+    (Modcod::Mc256apsk2_3L, Framesize::Normal, Constellation::Mod256apsk, CodeRate::C20_30),
+    (Modcod::Mc256apsk31_45L, Framesize::Normal, Constellation::Mod256apsk, CodeRate::C124_180),
+    (Modcod::Mc256apsk32_45, Framesize::Normal, Constellation::Mod256apsk, CodeRate::C128_180),
+    (Modcod::Mc256apsk11_15L, Framesize::Normal, Constellation::Mod256apsk, CodeRate::C22_30),
+    (Modcod::Mc256apsk3_4, Framesize::Normal, Constellation::Mod256apsk, CodeRate::C135_180),
+    (Modcod::McQpsk11_45S, Framesize::Short, Constellation::ModQpsk, CodeRate::C11_45),
+    (Modcod::McQpsk4_15S, Framesize::Short, Constellation::ModQpsk, CodeRate::C4_15),
+    (Modcod::McQpsk14_45S, Framesize::Short, Constellation::ModQpsk, CodeRate::C14_45),
+    (Modcod::McQpsk7_15S, Framesize::Short, Constellation::ModQpsk, CodeRate::C7_15),
+    (Modcod::McQpsk8_15S, Framesize::Short, Constellation::ModQpsk, CodeRate::C8_15),
+    (Modcod::McQpsk32_45S, Framesize::Short, Constellation::ModQpsk, CodeRate::C32_45),
+    (Modcod::Mc8psk7_15S, Framesize::Short, Constellation::Mod8psk, CodeRate::C7_15),
+    (Modcod::Mc8psk8_15S, Framesize::Short, Constellation::Mod8psk, CodeRate::C8_15),
+    (Modcod::Mc8psk26_45S, Framesize::Short, Constellation::Mod8psk, CodeRate::C26_45),
+    (Modcod::Mc8psk32_45S, Framesize::Short, Constellation::Mod8psk, CodeRate::C32_45),
+    (Modcod::Mc16apsk7_15S, Framesize::Short, Constellation::Mod16apsk, CodeRate::C7_15),
+    (Modcod::Mc16apsk8_15S, Framesize::Short, Constellation::Mod16apsk, CodeRate::C8_15),
+    (Modcod::Mc16apsk26_45S, Framesize::Short, Constellation::Mod16apsk, CodeRate::C26_45),
+    (Modcod::Mc16apsk3_5S, Framesize::Short, Constellation::Mod16apsk, CodeRate::C3_5),
+    (Modcod::Mc16apsk32_45S, Framesize::Short, Constellation::Mod16apsk, CodeRate::C32_45),
+    (Modcod::Mc32apsk2_3S, Framesize::Short, Constellation::Mod4_12_16apsk, CodeRate::C2_3),
+    (Modcod::Mc32apsk32_45S, Framesize::Short, Constellation::Mod4_8_4_16apsk, CodeRate::C32_45),
+    (Modcod::McVlsnrSet1, Framesize::Normal, Constellation::ModQpsk, CodeRate::C2_9Vlsnr),
+    (Modcod::McVlsnrSet1, Framesize::Medium, Constellation::ModBpsk, CodeRate::C1_5Medium),
+    (Modcod::McVlsnrSet1, Framesize::Medium, Constellation::ModBpsk, CodeRate::C11_45Medium),
+    (Modcod::McVlsnrSet1, Framesize::Medium, Constellation::ModBpsk, CodeRate::C1_3Medium),
+    (Modcod::McVlsnrSet1, Framesize::Short, Constellation::ModBpskSf2, CodeRate::C1_5VlsnrSf2),
+    (Modcod::McVlsnrSet1, Framesize::Short, Constellation::ModBpskSf2, CodeRate::C11_45VlsnrSf2),
+    (Modcod::McVlsnrSet2, Framesize::Short, Constellation::ModBpsk, CodeRate::C1_5Vlsnr),
+    (Modcod::McVlsnrSet2, Framesize::Short, Constellation::ModBpsk, CodeRate::C4_15Vlsnr),
+    (Modcod::McVlsnrSet2, Framesize::Short, Constellation::ModBpsk, CodeRate::C1_3Vlsnr),
+];
+
+impl Modcod {
+    /// VL-SNR frames always carry pilots, so their MODCODs are only returned with `pilots` set.
+    pub fn from_parts(
+        constellation: Constellation,
+        rate: CodeRate,
+        framesize: Framesize,
+        pilots: bool,
+    ) -> Option<Self> {
+        let s2 = DVB_S2_MODCODS
+            .iter()
+            .filter(|(_, _, c, r)| *c == constellation && *r == rate)
+            .find_map(|&(normal, short, _, _)| match framesize {
+                Framesize::Normal => Some(normal),
+                Framesize::Short => Some(short),
+                Framesize::Medium => None,
+            });
+        let modcod = s2.or_else(|| {
+            DVB_S2X_MODCODS
+                .iter()
+                .find(|(_, f, c, r)| *f == framesize && *c == constellation && *r == rate)
+                .map(|p| p.0)
+        })?;
+        match modcod {
+            Modcod::McVlsnrSet1 | Modcod::McVlsnrSet2 if !pilots => None,
+            m => Some(m),
+        }
+    }
+
+    /// Framesize, constellation and code rate of the MODCOD, unless it is a VL-SNR set, which
+    /// leaves them to the VL-SNR header
+    fn parts(&self) -> Option<(Framesize, Constellation, CodeRate)> {
+        if matches!(self, Modcod::McVlsnrSet1 | Modcod::McVlsnrSet2) {
+            return None;
+        }
+        DVB_S2_MODCODS
+            .iter()
+            .find_map(|&(n, s, c, r)| {
+                if n == *self {
+                    Some((Framesize::Normal, c, r))
+                } else if s == *self {
+                    Some((Framesize::Short, c, r))
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                DVB_S2X_MODCODS
+                    .iter()
+                    .find(|p| p.0 == *self)
+                    .map(|&(_, f, c, r)| (f, c, r))
+            })
+    }
+
+    pub fn constellation(&self) -> Option<Constellation> {
+        self.parts().map(|p| p.1)
+    }
+
+    pub fn code_rate(&self) -> Option<CodeRate> {
+        self.parts().map(|p| p.2)
+    }
+
+    pub fn framesize(&self) -> Option<Framesize> {
+        match self {
+            Modcod::McDummy => Some(Framesize::Normal),
+            Modcod::McDummyS => Some(Framesize::Short),
+            m => m.parts().map(|p| p.0),
+        }
+    }
+
+    /// PLS code: the MODCOD followed by the pilot flag. DVB-S2 codes are 7 bits, the 5-bit
+    /// MODCOD and the short frame flag, while DVB-S2X codes set the MSB of the 8 bits.
+    pub fn pls(&self, pilots: bool) -> u8 {
+        ((*self as u8) << 1) | pilots as u8
+    }
+}
+
+/// Decodes an 8-bit PLS code, DVB-S2 codes being those below 128; the pilot flag is its LSB. The
+/// VL-SNR codes are only valid with pilots.
+impl TryFrom<u8> for Modcod {
+    type Error = u8;
+
+    fn try_from(pls: u8) -> Result<Self, Self::Error> {
+        match pls >> 1 {
+            0 => Ok(Modcod::McDummy),
+            1 => Ok(Modcod::McDummyS),
+            m if pls & 0x80 == 0 => DVB_S2_MODCODS
+                .iter()
+                .flat_map(|&(n, s, _, _)| [n, s])
+                .find(|&x| x as u8 == m)
+                .ok_or(pls),
+            m => DVB_S2X_MODCODS
+                .iter()
+                .map(|p| p.0)
+                .find(|&x| x as u8 == m)
+                .filter(|x| pls & 1 != 0 || !matches!(x, Modcod::McVlsnrSet1 | Modcod::McVlsnrSet2))
+                .ok_or(pls),
+        }
+    }
+}
+
+/// Whether the PLS code `pls` signals the given FECFRAME size, code rate and constellation
+pub(super) fn pls_matches(
+    pls: usize,
+    framesize: Framesize,
+    rate: CodeRate,
+    constellation: Constellation,
+) -> bool {
+    let Some(modcod) = u8::try_from(pls)
+        .ok()
+        .and_then(|pls| Modcod::try_from(pls).ok())
+    else {
+        return false;
+    };
+    modcod.framesize() == Some(framesize)
+        && modcod.code_rate() == Some(rate)
+        && modcod.constellation() == Some(constellation)
+}
+
 #[derive(Clone, Copy)]
 pub enum VlsnrHeader {
     NormalQpsk2_9 = 0,
@@ -240,6 +558,37 @@ pub enum VlsnrHeader {
     Dummy,
 }
 
+/// Name of the `Tag::NamedUsize` carrying the PLS code at the first item of a frame
+pub const PLS_TAG: &str = "pls";
+/// Name of the `Tag::NamedAny` carrying the [`Modcod`] decoded from the PLS code, next to its
+/// [`PLS_TAG`]
+pub const MODCOD_TAG: &str = "modcod";
+/// Name of the `Tag::NamedUsize` carrying the pilot flag of the PLS code, `1` with pilots, next
+/// to its [`PLS_TAG`]
+pub const PILOTS_TAG: &str = "pilots";
+/// Name of the `Tag::NamedUsize` carrying the short frame flag of the PLS code, `1` for short
+/// FECFRAMEs, next to its [`PLS_TAG`]
+pub const SHORT_FRAME_TAG: &str = "short_frame";
+/// Name of the `Tag::NamedUsize` marking a frame whose PLHEADER was not detected, next to its
+/// [`PLS_TAG`], with the number of headers missed in a row
+pub const PLSYNC_MISS_TAG: &str = "plsync_miss";
+
+pub const GOLD_CODE_PERIOD: u32 = (1 << 18) - 1;
+
+/// Index `n` of the Gold code used for physical layer scrambling
+#[derive(Clone, Copy, Default)]
+pub struct GoldCode(u32);
+
+impl GoldCode {
+    pub fn new(n: u32) -> Option<Self> {
+        (n < GOLD_CODE_PERIOD).then_some(Self(n))
+    }
+
+    pub fn index(&self) -> u32 {
+        self.0
+    }
+}
+
 pub const NORMAL_PUNCTURING: usize = 3240;
 pub const MEDIUM_PUNCTURING: usize = 1620;
 pub const SHORT_PUNCTURING_SET1: usize = 810;
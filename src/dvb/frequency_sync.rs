@@ -0,0 +1,256 @@
+use std::f32::consts::PI;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::config::{GoldCode, PLSYNC_MISS_TAG, PLS_TAG};
+use super::pl_framer::{
+    pl_header, pl_scrambling_sequence, plframe_len, rotate, PILOT, PILOT_BLOCK_SIZE,
+    PILOT_PERIOD_SLOTS, PL_HEADER_SIZE, SLOT_SIZE,
+};
+
+/// Number of autocorrelation lags of the coarse estimator, which covers offsets of up to
+/// `2π / (LAGS + 1)` rad/symbol
+const LAGS: usize = 32;
+/// Distance in symbols between consecutive pilot blocks
+const PILOT_PERIOD: usize = PILOT_PERIOD_SLOTS * SLOT_SIZE + PILOT_BLOCK_SIZE;
+/// Unambiguous range in rad/symbol of the fine estimator between pilot blocks
+const FINE_RANGE: f32 = PI / PILOT_PERIOD as f32;
+/// Weight of the latest frame in the average of the coarse estimates, which reduces their noise
+/// enough to compare it with the range of the fine estimator at low SNR
+const COARSE_AVERAGING: f32 = 0.1;
+
+/// Known symbols of the current PLFRAME
+struct Frame {
+    pls: u8,
+    /// Number of PLHEADERs missed in a row by the [`PlSync`](super::PlSync), zero if the header
+    /// of this frame was detected
+    misses: usize,
+    len: usize,
+    pilots: bool,
+    /// Number of symbols of the frame seen so far
+    pos: usize,
+    header: Vec<Complex32>,
+    /// Received header symbols multiplied with the conjugated known symbols
+    header_z: Vec<Complex32>,
+    /// Sum of the received pilot symbols multiplied with the conjugated known symbols, one per
+    /// pilot block
+    pilot_z: Vec<Complex32>,
+}
+
+/// Carrier frequency recovery on PLFRAMEs tagged by the [`PlSync`](super::PlSync).
+///
+/// Symbols are derotated by an NCO. After every PLFRAME, the residual offset is estimated on the
+/// known symbols and fed to a first-order loop with gain `loop_gain`. The coarse estimator
+/// (Luise-Reggiannini on the PLHEADER) pulls in offsets of a few percent of the symbol rate. The
+/// fine estimator uses the phase differences between the PLHEADER and the pilot blocks. It takes
+/// over once the average of the coarse estimates is within half of its unambiguous range and hands
+/// back once the average leaves that range. Frames without pilots only use the coarse estimator,
+/// and frames whose PLHEADER the [`PlSync`](super::PlSync) missed do not update the loop.
+///
+/// The frequency estimate in cycles per symbol is posted on the `frequency` message port whenever
+/// it was updated.
+pub struct FrequencySync {
+    scrambling: Vec<u8>,
+    loop_gain: f32,
+    /// NCO frequency in rad/symbol
+    frequency: f32,
+    /// NCO phase in rad
+    phase: f32,
+    /// Average of the coarse estimates in rad/symbol
+    coarse_average: f32,
+    /// Whether the fine estimator is used
+    fine: bool,
+    frame: Option<Frame>,
+}
+
+impl FrequencySync {
+    pub fn new(gold_code: GoldCode, loop_gain: f32) -> Option<Block> {
+        if !(loop_gain > 0.0 && loop_gain <= 1.0) {
+            return None;
+        }
+        let max_len = (0..128).filter_map(plframe_len).max().unwrap();
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Frequency_Sync").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_output("frequency")
+                .build(),
+            Self {
+                scrambling: pl_scrambling_sequence(gold_code, max_len - PL_HEADER_SIZE),
+                loop_gain,
+                frequency: 0.0,
+                phase: 0.0,
+                coarse_average: 0.0,
+                fine: false,
+                frame: None,
+            },
+        ))
+    }
+
+    /// Records the derotated symbol `y` if it is a known symbol of the current frame.
+    fn observe(&mut self, y: Complex32) {
+        let Some(frame) = &mut self.frame else {
+            return;
+        };
+        if frame.pos < PL_HEADER_SIZE {
+            frame.header_z.push(y * frame.header[frame.pos].conj());
+        } else if frame.pilots {
+            let q = frame.pos - PL_HEADER_SIZE;
+            let block = q / PILOT_PERIOD;
+            let offset = q % PILOT_PERIOD;
+            if offset >= PILOT_PERIOD - PILOT_BLOCK_SIZE
+                && frame.len - PL_HEADER_SIZE - q > SLOT_SIZE
+            {
+                if frame.pilot_z.len() == block {
+                    frame.pilot_z.push(Complex32::new(0.0, 0.0));
+                }
+                frame.pilot_z[block] += y * rotate(PILOT, self.scrambling[q]).conj();
+            }
+        }
+        frame.pos += 1;
+    }
+
+    /// Estimates the residual offset of a complete frame and updates the NCO frequency.
+    fn update(&mut self, frame: &Frame) {
+        let z = &frame.header_z;
+        let r: Complex32 = (1..=LAGS)
+            .map(|m| {
+                (m..z.len())
+                    .map(|k| z[k] * z[k - m].conj())
+                    .sum::<Complex32>()
+                    / (z.len() - m) as f32
+            })
+            .sum();
+        let coarse = 2.0 * r.arg() / (LAGS + 1) as f32;
+        self.coarse_average += COARSE_AVERAGING * (coarse - self.coarse_average);
+        if self.coarse_average.abs() < FINE_RANGE / 2.0 {
+            self.fine = true;
+        } else if self.coarse_average.abs() > FINE_RANGE {
+            self.fine = false;
+        }
+
+        let error = if frame.pilot_z.is_empty() || !self.fine {
+            coarse
+        } else {
+            // Block phases at the block centers, relative to the start of the frame
+            let header = (
+                z.iter().sum::<Complex32>(),
+                (PL_HEADER_SIZE - 1) as f32 / 2.0,
+            );
+            let pilots = frame.pilot_z.iter().enumerate().map(|(b, &s)| {
+                let center = PL_HEADER_SIZE + (b + 1) * PILOT_PERIOD - PILOT_BLOCK_SIZE;
+                (s, center as f32 + (PILOT_BLOCK_SIZE - 1) as f32 / 2.0)
+            });
+            let blocks: Vec<(Complex32, f32)> = std::iter::once(header).chain(pilots).collect();
+            let (phase, distance) = blocks.windows(2).fold((0.0, 0.0), |(p, d), w| {
+                (p + (w[1].0 * w[0].0.conj()).arg(), d + w[1].1 - w[0].1)
+            });
+            phase / distance
+        };
+
+        self.frequency += self.loop_gain * error;
+    }
+}
+
+#[async_trait]
+impl Kernel for FrequencySync {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+
+        let m = std::cmp::min(i.len(), o.len());
+        let mut starts: Vec<(usize, usize)> = sio
+            .input(0)
+            .tags()
+            .iter()
+            .filter_map(|t| match &t.tag {
+                Tag::NamedUsize(name, pls) if t.index < m && name == PLS_TAG => {
+                    Some((t.index, *pls))
+                }
+                _ => None,
+            })
+            .collect();
+        starts.sort();
+        let misses: Vec<(usize, usize)> = sio
+            .input(0)
+            .tags()
+            .iter()
+            .filter_map(|t| match &t.tag {
+                Tag::NamedUsize(name, n) if t.index < m && name == PLSYNC_MISS_TAG => {
+                    Some((t.index, *n))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut updates = 0;
+        let mut starts = starts.into_iter().peekable();
+        for k in 0..m {
+            if let Some((_, pls)) = starts.next_if(|&(index, _)| index == k) {
+                let pls = u8::try_from(pls).ok();
+                self.frame = pls.and_then(|pls| {
+                    Some(Frame {
+                        pls,
+                        misses: misses
+                            .iter()
+                            .find_map(|&(index, n)| (index == k).then_some(n))
+                            .unwrap_or(0),
+                        len: plframe_len(pls)?,
+                        pilots: pls & 1 != 0,
+                        pos: 0,
+                        header: pl_header(pls),
+                        header_z: Vec::with_capacity(PL_HEADER_SIZE),
+                        pilot_z: Vec::new(),
+                    })
+                });
+                if let Some(frame) = &self.frame {
+                    sio.output(0)
+                        .add_tag(k, Tag::NamedUsize(PLS_TAG.to_string(), frame.pls as usize));
+                    if frame.misses > 0 {
+                        sio.output(0).add_tag(
+                            k,
+                            Tag::NamedUsize(PLSYNC_MISS_TAG.to_string(), frame.misses),
+                        );
+                    }
+                }
+            }
+
+            let y = i[k] * Complex32::from_polar(1.0, -self.phase);
+            o[k] = y;
+            self.observe(y);
+            self.phase = (self.phase + self.frequency + PI).rem_euclid(2.0 * PI) - PI;
+
+            if let Some(frame) = self.frame.take_if(|f| f.pos == f.len) {
+                if frame.misses == 0 {
+                    self.update(&frame);
+                    updates += 1;
+                }
+            }
+        }
+
+        sio.input(0).consume(m);
+        sio.output(0).produce(m);
+
+        if updates > 0 {
+            mio.post(0, Pmt::F32(self.frequency / (2.0 * PI))).await;
+        }
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
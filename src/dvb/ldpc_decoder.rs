@@ -0,0 +1,243 @@
+use std::marker::PhantomData;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::config::{CodeRate, Framesize};
+use super::ldpc_encoder::LdpcCode;
+
+/// Correction of the check node messages of the min-sum algorithm
+#[derive(Clone, Copy)]
+pub enum MinSum {
+    /// Messages scaled by a factor below one
+    Normalized(f32),
+    /// Messages reduced in magnitude by an offset, in units of the input LLRs
+    Offset(f32),
+}
+
+impl Default for MinSum {
+    fn default() -> Self {
+        MinSum::Normalized(0.9375)
+    }
+}
+
+impl MinSum {
+    fn correct(&self, magnitude: f32) -> f32 {
+        match *self {
+            MinSum::Normalized(a) => a * magnitude,
+            MinSum::Offset(b) => (magnitude - b).max(0.0),
+        }
+    }
+}
+
+/// Input type of the [`LdpcDecoder`]
+pub trait Llr: Copy + Send + Sync + 'static {
+    fn to_f32(self) -> f32;
+}
+
+impl Llr for f32 {
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+impl Llr for i8 {
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+}
+
+/// Parity check matrix in compressed row form with a layered min-sum decoder.
+///
+/// LLRs are `log(P(0) / P(1))`, i.e. positive values decode to 0.
+pub(super) struct LdpcDecoderCode {
+    pub k: usize,
+    pub n: usize,
+    /// Edges of check `c` are `offsets[c]..offsets[c + 1]`
+    offsets: Vec<usize>,
+    bits: Vec<u32>,
+    /// Check to bit messages, one per edge
+    messages: Vec<f32>,
+}
+
+impl LdpcDecoderCode {
+    pub fn new(code: LdpcCode) -> Self {
+        let checks = code.n - code.k;
+        let mut offsets = vec![0; checks + 1];
+        code.for_each_edge(|c, _| offsets[c + 1] += 1);
+        for c in 0..checks {
+            offsets[c + 1] += offsets[c];
+        }
+        let mut fill = offsets.clone();
+        let mut bits = vec![0; offsets[checks]];
+        code.for_each_edge(|c, b| {
+            bits[fill[c]] = b as u32;
+            fill[c] += 1;
+        });
+        Self {
+            k: code.k,
+            n: code.n,
+            messages: vec![0.0; bits.len()],
+            offsets,
+            bits,
+        }
+    }
+
+    fn failed_checks(&self, llr: &[f32]) -> usize {
+        self.offsets
+            .windows(2)
+            .filter(|e| {
+                self.bits[e[0]..e[1]]
+                    .iter()
+                    .fold(false, |p, &b| p ^ (llr[b as usize] < 0.0))
+            })
+            .count()
+    }
+
+    /// Decodes the frame in place, turning the channel LLRs into posterior LLRs.
+    ///
+    /// Returns the number of iterations and the number of unsatisfied parity checks.
+    pub fn decode(
+        &mut self,
+        llr: &mut [f32],
+        max_iterations: usize,
+        min_sum: MinSum,
+    ) -> (usize, usize) {
+        self.messages.fill(0.0);
+        let mut failed = self.failed_checks(llr);
+        let mut iterations = 0;
+        while failed > 0 && iterations < max_iterations {
+            for e in self.offsets.windows(2) {
+                let edges = e[0]..e[1];
+                let mut min1 = f32::INFINITY;
+                let mut min2 = f32::INFINITY;
+                let mut min_edge = 0;
+                let mut sign = false;
+                for j in edges.clone() {
+                    let b = self.bits[j] as usize;
+                    let t = llr[b] - self.messages[j];
+                    llr[b] = t;
+                    sign ^= t < 0.0;
+                    let a = t.abs();
+                    if a < min1 {
+                        min2 = min1;
+                        min1 = a;
+                        min_edge = j;
+                    } else if a < min2 {
+                        min2 = a;
+                    }
+                }
+                for j in edges {
+                    let b = self.bits[j] as usize;
+                    let t = llr[b];
+                    let mag = min_sum.correct(if j == min_edge { min2 } else { min1 });
+                    let m = if sign ^ (t < 0.0) { -mag } else { mag };
+                    self.messages[j] = m;
+                    llr[b] = t + m;
+                }
+            }
+            iterations += 1;
+            failed = self.failed_checks(llr);
+        }
+        (iterations, failed)
+    }
+}
+
+/// Soft-decision LDPC decoder.
+///
+/// Takes the `n` LLRs of a FECFRAME, as `f32` or quantized to `i8`, and outputs the `k`
+/// hard-decision information bits. The first bit of every frame is tagged with the number of
+/// iterations (`ldpc_iterations`) and the number of parity checks that are still unsatisfied
+/// (`ldpc_failed_checks`). The same codes as for the [`LdpcEncoder`](super::LdpcEncoder) are
+/// supported.
+pub struct LdpcDecoder<T: Llr = f32> {
+    code: LdpcDecoderCode,
+    max_iterations: usize,
+    min_sum: MinSum,
+    llr: Vec<f32>,
+    _input: PhantomData<T>,
+}
+
+impl<T: Llr> LdpcDecoder<T> {
+    pub fn new(
+        framesize: Framesize,
+        rate: CodeRate,
+        max_iterations: usize,
+        min_sum: MinSum,
+    ) -> Option<Block> {
+        let code = LdpcDecoderCode::new(LdpcCode::dvb(framesize, rate)?);
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_LDPC_Decoder").build(),
+            StreamIoBuilder::new()
+                .add_input::<T>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                llr: Vec::with_capacity(code.n),
+                code,
+                max_iterations,
+                min_sum,
+                _input: PhantomData,
+            },
+        ))
+    }
+}
+
+#[async_trait]
+impl<T: Llr> Kernel for LdpcDecoder<T> {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<T>();
+        let o = sio.output(0).slice::<u8>();
+        let LdpcDecoderCode { k, n, .. } = self.code;
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if self.llr.len() == n {
+                if o.len() - produced < k {
+                    break;
+                }
+                let (iterations, failed) =
+                    self.code
+                        .decode(&mut self.llr, self.max_iterations, self.min_sum);
+                for (b, &l) in o[produced..produced + k].iter_mut().zip(&self.llr) {
+                    *b = (l < 0.0) as u8;
+                }
+                sio.output(0).add_tag(
+                    produced,
+                    Tag::NamedUsize("ldpc_iterations".to_string(), iterations),
+                );
+                sio.output(0).add_tag(
+                    produced,
+                    Tag::NamedUsize("ldpc_failed_checks".to_string(), failed),
+                );
+                self.llr.clear();
+                produced += k;
+            } else if consumed < i.len() {
+                let m = std::cmp::min(n - self.llr.len(), i.len() - consumed);
+                self.llr
+                    .extend(i[consumed..consumed + m].iter().map(|l| l.to_f32()));
+                consumed += m;
+            } else {
+                break;
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished = sio.input(0).finished() && consumed == i.len() && self.llr.len() < n;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::config::{CodeRate, Framesize, FRAME_SIZE_NORMAL, FRAME_SIZE_SHORT};
+use super::ldpc_tables::*;
+
+const GROUP_SIZE: usize = 360;
+
+pub(super) struct LdpcCode {
+    pub k: usize,
+    pub n: usize,
+    pub q: usize,
+    pub table: &'static [&'static [u16]],
+}
+
+impl LdpcCode {
+    fn new(n: usize, table: &'static [&'static [u16]]) -> Self {
+        let k = table.len() * GROUP_SIZE;
+        Self {
+            k,
+            n,
+            q: (n - k) / GROUP_SIZE,
+            table,
+        }
+    }
+
+    /// Parity address tables of EN 302 307-1 for the DVB-S2 code rates; see [`LdpcEncoder`].
+    pub fn dvb(framesize: Framesize, rate: CodeRate) -> Option<Self> {
+        Some(match framesize {
+            Framesize::Normal => Self::new(
+                FRAME_SIZE_NORMAL,
+                match rate {
+                    CodeRate::C1_4 => LDPC_TAB_1_4N,
+                    CodeRate::C1_3 => LDPC_TAB_1_3N,
+                    CodeRate::C2_5 => LDPC_TAB_2_5N,
+                    CodeRate::C1_2 => LDPC_TAB_1_2N,
+                    CodeRate::C3_5 => LDPC_TAB_3_5N,
+                    CodeRate::C2_3 => LDPC_TAB_2_3N,
+                    CodeRate::C3_4 => LDPC_TAB_3_4N,
+                    CodeRate::C4_5 => LDPC_TAB_4_5N,
+                    CodeRate::C5_6 => LDPC_TAB_5_6N,
+                    CodeRate::C8_9 => LDPC_TAB_8_9N,
+                    CodeRate::C9_10 => LDPC_TAB_9_10N,
+                    _ => return None,
+                },
+            ),
+            Framesize::Short => Self::new(
+                FRAME_SIZE_SHORT,
+                match rate {
+                    CodeRate::C1_4 => LDPC_TAB_1_4S,
+                    CodeRate::C1_3 => LDPC_TAB_1_3S,
+                    CodeRate::C2_5 => LDPC_TAB_2_5S,
+                    CodeRate::C1_2 => LDPC_TAB_1_2S,
+                    CodeRate::C3_5 => LDPC_TAB_3_5S,
+                    CodeRate::C2_3 => LDPC_TAB_2_3S,
+                    CodeRate::C3_4 => LDPC_TAB_3_4S,
+                    CodeRate::C4_5 => LDPC_TAB_4_5S,
+                    CodeRate::C5_6 => LDPC_TAB_5_6S,
+                    CodeRate::C8_9 => LDPC_TAB_8_9S,
+                    _ => return None,
+                },
+            ),
+            Framesize::Medium => return None,
+        })
+    }
+
+    pub fn encode(&self, info: &[u8], parity: &mut [u8]) {
+        let pn = self.n - self.k;
+        parity.fill(0);
+        for (row, bits) in self.table.iter().zip(info.chunks_exact(GROUP_SIZE)) {
+            for (w, &b) in bits.iter().enumerate() {
+                if b != 0 {
+                    for &x in row.iter() {
+                        parity[(x as usize + w * self.q) % pn] ^= 1;
+                    }
+                }
+            }
+        }
+        for j in 1..pn {
+            parity[j] ^= parity[j - 1];
+        }
+    }
+
+    /// Calls `f(check, bit)` for every edge of the parity check matrix.
+    pub fn for_each_edge(&self, mut f: impl FnMut(usize, usize)) {
+        let pn = self.n - self.k;
+        for (g, row) in self.table.iter().enumerate() {
+            for w in 0..GROUP_SIZE {
+                for &x in row.iter() {
+                    f((x as usize + w * self.q) % pn, g * GROUP_SIZE + w);
+                }
+            }
+        }
+        for j in 0..pn {
+            if j > 0 {
+                f(j, self.k + j - 1);
+            }
+            f(j, self.k + j);
+        }
+    }
+}
+
+/// Systematic LDPC encoder of DVB-S2, taking the `k` bits of a BCH codeword and outputting the
+/// `n`-bit FECFRAME.
+///
+/// Covers the DVB-S2 code rates of normal and short FECFRAMEs (EN 302 307-1, Annexes B and C).
+/// The parity address tables of the DVB-S2X code rates and of medium FECFRAMEs (EN 302 307-2)
+/// are not implemented, so [`LdpcEncoder::new`] returns `None` for them even though the
+/// [`BchEncoder`](super::BchEncoder) accepts them. The [`LdpcDecoder`](super::LdpcDecoder) has the
+/// same limits.
+pub struct LdpcEncoder(LdpcCode);
+
+impl LdpcEncoder {
+    pub fn new(framesize: Framesize, rate: CodeRate) -> Option<Block> {
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_LDPC_Encoder").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<u8>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self(LdpcCode::dvb(framesize, rate)?),
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for LdpcEncoder {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<u8>();
+        let LdpcCode { k, n, .. } = self.0;
+
+        let m = std::cmp::min(i.len() / k, o.len() / n);
+        if m > 0 {
+            for (v, r) in i.chunks_exact(k).zip(o.chunks_exact_mut(n)) {
+                let (info, par) = r.split_at_mut(k);
+                info.copy_from_slice(v);
+                self.0.encode(v, par);
+            }
+
+            sio.input(0).consume(k * m);
+            sio.output(0).produce(n * m);
+        }
+
+        io.finished = sio.input(0).finished() && i.len() - k * m < k;
+        Ok(())
+    }
+}
@@ -1,4 +1,49 @@
+mod agc;
+mod bb_deframer;
+mod bb_header;
+mod bb_scrambler;
+mod bch_decoder;
 mod bch_encoder;
+mod bit_deinterleaver;
+mod bit_interleaver;
+mod frequency_sync;
+mod ldpc_decoder;
+mod ldpc_encoder;
+mod ldpc_tables;
+mod modulator;
+mod phase_tracker;
+mod pl_descrambler;
+mod pl_framer;
+mod pl_sync;
+mod predistorter;
+mod pulse_shaper;
+mod receiver;
+mod soft_demapper;
+mod symbol_sync;
+mod transmitter;
 
 pub mod config;
+pub use agc::Agc;
+pub use bb_deframer::BbDeframer;
+pub use bb_header::BbHeader;
+pub use bb_scrambler::BbScrambler;
+/// BB scrambling is its own inverse.
+pub use bb_scrambler::BbScrambler as BbDescrambler;
+pub use bch_decoder::BchDecoder;
 pub use bch_encoder::BchEncoder;
+pub use bit_deinterleaver::BitDeinterleaver;
+pub use bit_interleaver::BitInterleaver;
+pub use frequency_sync::FrequencySync;
+pub use ldpc_decoder::{LdpcDecoder, Llr, MinSum};
+pub use ldpc_encoder::LdpcEncoder;
+pub use modulator::Modulator;
+pub use phase_tracker::PhaseTracker;
+pub use pl_descrambler::PlDescrambler;
+pub use pl_framer::PhysicalLayerFramer;
+pub use pl_sync::PlSync;
+pub use predistorter::{Predistorter, PredistortionTable};
+pub use pulse_shaper::PulseShaper;
+pub use receiver::Receiver;
+pub use soft_demapper::{Demapping, SoftDemapper};
+pub use symbol_sync::SymbolSync;
+pub use transmitter::Transmitter;
@@ -0,0 +1,225 @@
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_8, PI};
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::bit_interleaver::Interleaving;
+use super::config::{CodeRate, Constellation, Framesize};
+
+const FRAC_PI_12: f32 = PI / 12.0;
+
+fn polar(r: f32, phi: f32) -> Complex32 {
+    Complex32::from_polar(r, phi)
+}
+
+/// Constellation points indexed by the symbol bits, scaled to unit average power.
+///
+/// π/2-BPSK returns the points of the even symbols; odd symbols are rotated by π/2. Only the
+/// DVB-S2 code rates of 16APSK and 32APSK have ring ratios; see [`Modulator`] for the rest.
+pub(super) fn constellation_points(
+    rate: CodeRate,
+    constellation: Constellation,
+) -> Option<Vec<Complex32>> {
+    Some(match constellation {
+        Constellation::ModBpsk | Constellation::ModBpskSf2 => {
+            vec![polar(1.0, FRAC_PI_4), polar(1.0, 5.0 * FRAC_PI_4)]
+        }
+        Constellation::ModQpsk => vec![
+            polar(1.0, FRAC_PI_4),
+            polar(1.0, 7.0 * FRAC_PI_4),
+            polar(1.0, 3.0 * FRAC_PI_4),
+            polar(1.0, 5.0 * FRAC_PI_4),
+        ],
+        Constellation::Mod8psk => vec![
+            polar(1.0, FRAC_PI_4),
+            polar(1.0, 0.0),
+            polar(1.0, PI),
+            polar(1.0, 5.0 * FRAC_PI_4),
+            polar(1.0, FRAC_PI_2),
+            polar(1.0, 7.0 * FRAC_PI_4),
+            polar(1.0, 3.0 * FRAC_PI_4),
+            polar(1.0, 3.0 * FRAC_PI_2),
+        ],
+        Constellation::Mod16apsk => {
+            let gamma: f32 = match rate {
+                CodeRate::C2_3 => 3.15,
+                CodeRate::C3_4 => 2.85,
+                CodeRate::C4_5 => 2.75,
+                CodeRate::C5_6 => 2.70,
+                CodeRate::C8_9 => 2.60,
+                CodeRate::C9_10 => 2.57,
+                _ => return None,
+            };
+            let r1 = (4.0 / (1.0 + 3.0 * gamma * gamma)).sqrt();
+            let r2 = gamma * r1;
+            vec![
+                polar(r2, FRAC_PI_4),
+                polar(r2, -FRAC_PI_4),
+                polar(r2, 3.0 * FRAC_PI_4),
+                polar(r2, -3.0 * FRAC_PI_4),
+                polar(r2, FRAC_PI_12),
+                polar(r2, -FRAC_PI_12),
+                polar(r2, 11.0 * FRAC_PI_12),
+                polar(r2, -11.0 * FRAC_PI_12),
+                polar(r2, 5.0 * FRAC_PI_12),
+                polar(r2, -5.0 * FRAC_PI_12),
+                polar(r2, 7.0 * FRAC_PI_12),
+                polar(r2, -7.0 * FRAC_PI_12),
+                polar(r1, FRAC_PI_4),
+                polar(r1, -FRAC_PI_4),
+                polar(r1, 3.0 * FRAC_PI_4),
+                polar(r1, -3.0 * FRAC_PI_4),
+            ]
+        }
+        Constellation::Mod32apsk => {
+            let (gamma1, gamma2): (f32, f32) = match rate {
+                CodeRate::C3_4 => (2.84, 5.27),
+                CodeRate::C4_5 => (2.72, 4.87),
+                CodeRate::C5_6 => (2.64, 4.64),
+                CodeRate::C8_9 => (2.54, 4.33),
+                CodeRate::C9_10 => (2.53, 4.30),
+                _ => return None,
+            };
+            let r1 = (32.0 / (4.0 + 12.0 * gamma1 * gamma1 + 16.0 * gamma2 * gamma2)).sqrt();
+            let r2 = gamma1 * r1;
+            let r3 = gamma2 * r1;
+            vec![
+                polar(r2, FRAC_PI_4),
+                polar(r2, 5.0 * FRAC_PI_12),
+                polar(r2, -FRAC_PI_4),
+                polar(r2, -5.0 * FRAC_PI_12),
+                polar(r2, 3.0 * FRAC_PI_4),
+                polar(r2, 7.0 * FRAC_PI_12),
+                polar(r2, -3.0 * FRAC_PI_4),
+                polar(r2, -7.0 * FRAC_PI_12),
+                polar(r3, FRAC_PI_8),
+                polar(r3, 3.0 * FRAC_PI_8),
+                polar(r3, -FRAC_PI_4),
+                polar(r3, -FRAC_PI_2),
+                polar(r3, 3.0 * FRAC_PI_4),
+                polar(r3, FRAC_PI_2),
+                polar(r3, -7.0 * FRAC_PI_8),
+                polar(r3, -5.0 * FRAC_PI_8),
+                polar(r2, FRAC_PI_12),
+                polar(r1, FRAC_PI_4),
+                polar(r2, -FRAC_PI_12),
+                polar(r1, -FRAC_PI_4),
+                polar(r2, 11.0 * FRAC_PI_12),
+                polar(r1, 3.0 * FRAC_PI_4),
+                polar(r2, -11.0 * FRAC_PI_12),
+                polar(r1, -3.0 * FRAC_PI_4),
+                polar(r3, 0.0),
+                polar(r3, FRAC_PI_4),
+                polar(r3, -FRAC_PI_8),
+                polar(r3, -3.0 * FRAC_PI_8),
+                polar(r3, 7.0 * FRAC_PI_8),
+                polar(r3, 5.0 * FRAC_PI_8),
+                polar(r3, PI),
+                polar(r3, -3.0 * FRAC_PI_4),
+            ]
+        }
+        Constellation::Mod8apsk
+        | Constellation::Mod8_8apsk
+        | Constellation::Mod4_12_16apsk
+        | Constellation::Mod4_8_4_16apsk
+        | Constellation::Mod64apsk
+        | Constellation::Mod8_16_20_20apsk
+        | Constellation::Mod4_12_20_28apsk
+        | Constellation::Mod128apsk
+        | Constellation::Mod256apsk
+        | Constellation::ModOther => return None,
+    })
+}
+
+/// Maps groups of interleaved bits to unit average power constellation points.
+///
+/// Covers π/2-BPSK, BPSK-SF2, QPSK and 8PSK at every code rate, and 16APSK and 32APSK at the
+/// DVB-S2 code rates. The DVB-S2X APSK constellations and the ring ratios of the DVB-S2X code rates
+/// (EN 302 307-2, Tables 13 to 17) are not implemented, and [`Modulator::new`] returns `None` for
+/// them, as do the [`SoftDemapper`](super::SoftDemapper) and the
+/// [`Predistorter`](super::Predistorter). The [`PhaseTracker`](super::PhaseTracker) tracks such
+/// frames on their pilots only.
+pub struct Modulator {
+    points: Vec<Complex32>,
+    /// π/2-BPSK rotates every odd symbol of the XFECFRAME
+    pi2: bool,
+    /// BPSK-SF2 maps every bit onto two consecutive symbols
+    spread: usize,
+    /// Number of output symbols per XFECFRAME
+    frame_len: usize,
+    index: usize,
+}
+
+impl Modulator {
+    pub fn new(
+        framesize: Framesize,
+        rate: CodeRate,
+        constellation: Constellation,
+    ) -> Option<Block> {
+        let interleaving = Interleaving::dvb(framesize, rate, constellation)?;
+        let pi2 = matches!(
+            constellation,
+            Constellation::ModBpsk | Constellation::ModBpskSf2
+        );
+        let spread = if matches!(constellation, Constellation::ModBpskSf2) {
+            2
+        } else {
+            1
+        };
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Modulator").build(),
+            StreamIoBuilder::new()
+                .add_input::<u8>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                points: constellation_points(rate, constellation)?,
+                pi2,
+                spread,
+                frame_len: interleaving.n / interleaving.bits * spread,
+                index: 0,
+            },
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for Modulator {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<u8>();
+        let o = sio.output(0).slice::<Complex32>();
+
+        let m = std::cmp::min(i.len(), o.len() / self.spread);
+        if m > 0 {
+            for (&s, r) in i.iter().zip(o.chunks_exact_mut(self.spread)) {
+                let p = self.points[s as usize];
+                for x in r {
+                    *x = if self.pi2 && self.index % 2 == 1 {
+                        Complex32::new(-p.im, p.re)
+                    } else {
+                        p
+                    };
+                    self.index = (self.index + 1) % self.frame_len;
+                }
+            }
+
+            sio.input(0).consume(m);
+            sio.output(0).produce(m * self.spread);
+        }
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::config::{Constellation, GoldCode, Modcod, PLSYNC_MISS_TAG, PLS_TAG};
+use super::modulator::constellation_points;
+use super::pl_framer::{
+    pl_header, pl_scrambling_sequence, plframe_len, rotate, PILOT, PILOT_BLOCK_SIZE,
+    PILOT_PERIOD_SLOTS, PL_HEADER_SIZE, SLOT_SIZE,
+};
+
+/// Distance in symbols between consecutive pilot blocks
+const PILOT_PERIOD: usize = PILOT_PERIOD_SLOTS * SLOT_SIZE + PILOT_BLOCK_SIZE;
+/// Gain of the decision-directed loop between known symbols
+const DD_GAIN: f32 = 0.05;
+/// Smallest product of the Es/N0 and the squared half minimum distance of the constellation for
+/// which the decision-directed loop is used. Below, wrong decisions make it slip.
+const DD_RELIABILITY: f32 = 1.5;
+
+/// Minimum distance between the points of a constellation
+fn min_distance(points: &[Complex32]) -> f32 {
+    points
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| points[i + 1..].iter().map(move |b| (a - b).norm()))
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Phase of a block of known symbols at its center
+struct Anchor {
+    center: f32,
+    phase: f32,
+    /// Energy of the mean symbol over the block
+    signal: f32,
+    /// Energy of the deviations from the mean symbol
+    noise: f32,
+}
+
+impl Anchor {
+    fn new(center: f32, y: &[Complex32], known: impl Iterator<Item = Complex32>) -> Self {
+        let z: Vec<Complex32> = y.iter().zip(known).map(|(y, k)| y * k.conj()).collect();
+        let mean = z.iter().sum::<Complex32>() / z.len() as f32;
+        Self {
+            center,
+            phase: mean.arg(),
+            signal: mean.norm_sqr() * z.len() as f32,
+            noise: z.iter().map(|z| (z - mean).norm_sqr()).sum(),
+        }
+    }
+}
+
+/// Carrier phase recovery on PLFRAMEs tagged by the [`PlSync`](super::PlSync).
+///
+/// The phase is estimated on the PLHEADER, the pilot blocks and the PLHEADER of the next frame,
+/// if it follows directly, and interpolated linearly across the data slots in between. With
+/// `decision_directed`, a first-order loop on the hard decisions of the data symbols additionally
+/// tracks the phase between the known symbols, which helps frames without pilots and with strong
+/// phase noise. It is only used on frames whose Es/N0, estimated on the known symbols, is high
+/// enough for reliable decisions in their constellation.
+///
+/// Frames are output completely, still scrambled and with PLHEADER and pilots, with their PLS and
+/// PL sync miss tags forwarded. Symbols outside of tagged frames are passed on unchanged.
+pub struct PhaseTracker {
+    scrambling: Vec<u8>,
+    decision_directed: bool,
+    /// Received symbols, starting with the next frame to track
+    buffer: Vec<Complex32>,
+    /// PLS tags with their index in `buffer` and the number of missed PLHEADERs
+    starts: VecDeque<(usize, u8, usize)>,
+    output: Vec<Complex32>,
+    /// PLS code to tag at the first symbol of `output`
+    output_pls: Option<u8>,
+    /// Number of missed PLHEADERs to tag with the PLS code
+    output_misses: usize,
+    /// Number of `output` symbols already produced
+    pos: usize,
+}
+
+impl PhaseTracker {
+    pub fn new(gold_code: GoldCode, decision_directed: bool) -> Block {
+        let max_len = (0..128).filter_map(plframe_len).max().unwrap();
+        Block::new(
+            BlockMetaBuilder::new("DVB_Phase_Tracker").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                scrambling: pl_scrambling_sequence(gold_code, max_len - PL_HEADER_SIZE),
+                decision_directed,
+                buffer: Vec::new(),
+                starts: VecDeque::new(),
+                output: Vec::new(),
+                output_pls: None,
+                output_misses: 0,
+                pos: 0,
+            },
+        )
+    }
+
+    /// Length of the frame at the start of `buffer` if it can be tracked, or the number of
+    /// untagged symbols that can be passed on.
+    fn next_output(&self, finished: bool) -> Option<Result<usize, usize>> {
+        match self.starts.front() {
+            Some(&(0, pls, _)) => {
+                let len = plframe_len(pls)?;
+                let next_start = self.starts.get(1).is_some_and(|&(i, _, _)| i == len);
+                if self.buffer.len() >= len + PL_HEADER_SIZE
+                    || (self.buffer.len() > len && !next_start)
+                    || (self.buffer.len() == len && finished)
+                {
+                    Some(Ok(len))
+                } else if finished {
+                    // Truncated frame at the end of the stream
+                    Some(Err(self.buffer.len()))
+                } else {
+                    None
+                }
+            }
+            Some(&(start, _, _)) => Some(Err(start)),
+            None if !self.buffer.is_empty() => Some(Err(self.buffer.len())),
+            None => None,
+        }
+    }
+
+    fn track(&mut self, len: usize) {
+        let (_, pls, misses) = self.starts.pop_front().unwrap();
+        let payload_len = len - PL_HEADER_SIZE;
+        let header = pl_header(pls);
+
+        let mut anchors = vec![Anchor::new(
+            (PL_HEADER_SIZE - 1) as f32 / 2.0,
+            &self.buffer[..PL_HEADER_SIZE],
+            header.iter().copied(),
+        )];
+        let modcod = Modcod::try_from(pls).ok();
+        let dummy = matches!(modcod, Some(Modcod::McDummy | Modcod::McDummyS));
+        if pls & 1 != 0 && !dummy {
+            let mut start = PILOT_PERIOD - PILOT_BLOCK_SIZE;
+            while start + PILOT_BLOCK_SIZE + SLOT_SIZE <= payload_len {
+                let known = self.scrambling[start..start + PILOT_BLOCK_SIZE]
+                    .iter()
+                    .map(|&r| rotate(PILOT, r));
+                let offset = PL_HEADER_SIZE + start;
+                anchors.push(Anchor::new(
+                    offset as f32 + (PILOT_BLOCK_SIZE - 1) as f32 / 2.0,
+                    &self.buffer[offset..offset + PILOT_BLOCK_SIZE],
+                    known,
+                ));
+                start += PILOT_PERIOD;
+            }
+        }
+        if let Some(&(start, next, _)) = self.starts.front() {
+            if start == len && self.buffer.len() >= len + PL_HEADER_SIZE {
+                anchors.push(Anchor::new(
+                    len as f32 + (PL_HEADER_SIZE - 1) as f32 / 2.0,
+                    &self.buffer[len..len + PL_HEADER_SIZE],
+                    pl_header(next).into_iter(),
+                ));
+            }
+        }
+        for k in 1..anchors.len() {
+            let d = anchors[k].phase - anchors[k - 1].phase;
+            anchors[k].phase = anchors[k - 1].phase + (d + PI).rem_euclid(2.0 * PI) - PI;
+        }
+
+        // Data symbols of the XFECFRAME for the decision-directed loop
+        let esn0 = anchors.iter().map(|a| a.signal).sum::<f32>()
+            / anchors.iter().map(|a| a.noise).sum::<f32>();
+        let points = modcod
+            .filter(|_| self.decision_directed && !dummy)
+            .and_then(|m| constellation_points(m.code_rate()?, m.constellation()?))
+            .filter(|p| esn0 * (min_distance(p) / 2.0).powi(2) >= DD_RELIABILITY);
+        let pi2 = matches!(
+            modcod.and_then(|m| m.constellation()),
+            Some(Constellation::ModBpsk | Constellation::ModBpskSf2)
+        );
+
+        self.output.clear();
+        let mut anchor = 0;
+        let mut psi = 0.0;
+        let mut data_index = 0;
+        for (p, &y) in self.buffer[..len].iter().enumerate() {
+            let t = p as f32;
+            while anchor + 1 < anchors.len() && anchors[anchor + 1].center <= t {
+                anchor += 1;
+                psi = 0.0;
+            }
+            let a = &anchors[anchor];
+            let theta = match anchors.get(anchor + 1) {
+                Some(b) if t > a.center => {
+                    a.phase + (b.phase - a.phase) * (t - a.center) / (b.center - a.center)
+                }
+                _ => a.phase,
+            };
+            let z = y * Complex32::from_polar(1.0, -(theta + psi));
+
+            if let Some(points) = &points {
+                let q = p.wrapping_sub(PL_HEADER_SIZE);
+                let pilot = pls & 1 != 0 && q % PILOT_PERIOD >= PILOT_PERIOD - PILOT_BLOCK_SIZE;
+                if p >= PL_HEADER_SIZE && !pilot {
+                    let x = rotate(z, (4 - self.scrambling[q]) % 4);
+                    let decision = points
+                        .iter()
+                        .map(|&s| {
+                            if pi2 && data_index % 2 == 1 {
+                                Complex32::new(-s.im, s.re)
+                            } else {
+                                s
+                            }
+                        })
+                        .min_by(|a, b| (x - a).norm_sqr().total_cmp(&(x - b).norm_sqr()))
+                        .unwrap();
+                    psi += DD_GAIN * (x * decision.conj()).arg();
+                    data_index += 1;
+                }
+            }
+            self.output.push(z);
+        }
+
+        self.buffer.drain(..len);
+        for s in self.starts.iter_mut() {
+            s.0 -= len;
+        }
+        self.output_pls = Some(pls);
+        self.output_misses = misses;
+        self.pos = 0;
+    }
+
+    fn pass(&mut self, n: usize) {
+        self.output.clear();
+        self.output.extend(self.buffer.drain(..n));
+        self.starts.retain(|s| s.0 >= n);
+        for s in self.starts.iter_mut() {
+            s.0 -= n;
+        }
+        self.output_pls = None;
+        self.pos = 0;
+    }
+}
+
+#[async_trait]
+impl Kernel for PhaseTracker {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let finished = sio.input(0).finished();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if self.pos < self.output.len() {
+                let n = std::cmp::min(self.output.len() - self.pos, o.len() - produced);
+                if n == 0 {
+                    break;
+                }
+                if self.pos == 0 {
+                    if let Some(pls) = self.output_pls.take() {
+                        sio.output(0)
+                            .add_tag(produced, Tag::NamedUsize(PLS_TAG.to_string(), pls as usize));
+                        if self.output_misses > 0 {
+                            sio.output(0).add_tag(
+                                produced,
+                                Tag::NamedUsize(PLSYNC_MISS_TAG.to_string(), self.output_misses),
+                            );
+                        }
+                    }
+                }
+                o[produced..produced + n].copy_from_slice(&self.output[self.pos..self.pos + n]);
+                self.pos += n;
+                produced += n;
+            } else if consumed < i.len() {
+                let mut tags: Vec<(usize, u8)> = sio
+                    .input(0)
+                    .tags()
+                    .iter()
+                    .filter_map(|t| match &t.tag {
+                        Tag::NamedUsize(name, pls) if name == PLS_TAG => {
+                            Some((t.index, u8::try_from(*pls).ok()?))
+                        }
+                        _ => None,
+                    })
+                    .filter(|&(index, pls)| index < i.len() && plframe_len(pls).is_some())
+                    .collect();
+                tags.sort();
+                let input_tags = sio.input(0).tags();
+                let misses = |index: usize| {
+                    input_tags
+                        .iter()
+                        .find_map(|t| match &t.tag {
+                            Tag::NamedUsize(name, n)
+                                if t.index == index && name == PLSYNC_MISS_TAG =>
+                            {
+                                Some(*n)
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(0)
+                };
+                let offset = self.buffer.len();
+                self.starts.extend(
+                    tags.into_iter()
+                        .map(|(index, pls)| (offset + index, pls, misses(index))),
+                );
+                self.buffer.extend_from_slice(i);
+                consumed = i.len();
+            } else {
+                match self.next_output(finished) {
+                    Some(Ok(len)) => self.track(len),
+                    Some(Err(n)) => self.pass(n),
+                    None => break,
+                }
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished = finished
+            && consumed == i.len()
+            && self.pos == self.output.len()
+            && self.buffer.is_empty();
+        Ok(())
+    }
+}
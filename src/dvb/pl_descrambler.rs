@@ -0,0 +1,187 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::log::warn;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::config::{GoldCode, Modcod, PLSYNC_MISS_TAG, PLS_TAG};
+use super::pl_framer::{
+    pl_scrambling_sequence, plframe_len, rotate, PILOT_BLOCK_SIZE, PILOT_PERIOD_SLOTS,
+    PL_HEADER_SIZE, SLOT_SIZE,
+};
+
+/// Inverse of the [`PhysicalLayerFramer`](super::PhysicalLayerFramer) after frame
+/// synchronization.
+///
+/// Every PLFRAME has to start with a PLS tag, as output by the [`PlSync`](super::PlSync). The
+/// PLHEADER is removed, the rest of the frame descrambled and its pilot blocks stripped, leaving
+/// the XFECFRAME symbols on `out`. The pilots are not output, as the
+/// [`PhaseTracker`](super::PhaseTracker) already uses them before descrambling. The PLS tag is
+/// forwarded to the first symbol of the XFECFRAME. Dummy frames and symbols outside of tagged
+/// frames are dropped.
+///
+/// Frames whose PLHEADER the `PlSync` missed are passed through with the PLS of the previous
+/// frame, so a constant MODCOD stream keeps them. Their `plsync_miss` tag is forwarded next to the
+/// PLS tag for downstream blocks that would rather drop them.
+pub struct PlDescrambler {
+    scrambling: Vec<u8>,
+    pls: u8,
+    /// Number of missed PLHEADERs in a row if the PLHEADER of the current PLFRAME was missed
+    misses: Option<usize>,
+    /// Length of the current PLFRAME, or zero while waiting for a PLS tag
+    frame_len: usize,
+    plframe: Vec<Complex32>,
+    data: Vec<Complex32>,
+    /// Number of `data` symbols already produced
+    data_pos: usize,
+}
+
+impl PlDescrambler {
+    pub fn new(gold_code: GoldCode) -> Block {
+        let max_len = (0..128).filter_map(plframe_len).max().unwrap();
+        Block::new(
+            BlockMetaBuilder::new("DVB_PL_Descrambler").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                scrambling: pl_scrambling_sequence(gold_code, max_len - PL_HEADER_SIZE),
+                pls: 0,
+                misses: None,
+                frame_len: 0,
+                plframe: Vec::with_capacity(max_len),
+                data: Vec::new(),
+                data_pos: 0,
+            },
+        )
+    }
+
+    fn split_frame(&mut self) {
+        self.data.clear();
+        let modcod = Modcod::try_from(self.pls).ok();
+        if !matches!(modcod, Some(Modcod::McDummy | Modcod::McDummyS)) {
+            let pilots = self.pls & 1 != 0;
+            let mut payload = self.plframe[PL_HEADER_SIZE..]
+                .iter()
+                .zip(&self.scrambling)
+                .map(|(&s, &r)| rotate(s, (4 - r) % 4));
+            for slot in 0.. {
+                if pilots && slot > 0 && slot % PILOT_PERIOD_SLOTS == 0 {
+                    payload.by_ref().take(PILOT_BLOCK_SIZE).for_each(drop);
+                }
+                let len = self.data.len();
+                self.data.extend(payload.by_ref().take(SLOT_SIZE));
+                if self.data.len() == len {
+                    break;
+                }
+            }
+        }
+        self.plframe.clear();
+        self.frame_len = 0;
+        self.data_pos = 0;
+    }
+}
+
+#[async_trait]
+impl Kernel for PlDescrambler {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if self.data_pos < self.data.len() {
+                let n = std::cmp::min(self.data.len() - self.data_pos, o.len() - produced);
+                if n == 0 {
+                    break;
+                }
+                if self.data_pos == 0 {
+                    sio.output(0).add_tag(
+                        produced,
+                        Tag::NamedUsize(PLS_TAG.to_string(), self.pls as usize),
+                    );
+                    if let Some(misses) = self.misses {
+                        sio.output(0).add_tag(
+                            produced,
+                            Tag::NamedUsize(PLSYNC_MISS_TAG.to_string(), misses),
+                        );
+                    }
+                }
+                o[produced..produced + n]
+                    .copy_from_slice(&self.data[self.data_pos..self.data_pos + n]);
+                self.data_pos += n;
+                produced += n;
+            } else if self.frame_len > 0 && self.plframe.len() == self.frame_len {
+                self.split_frame();
+            } else if consumed < i.len() {
+                if self.plframe.is_empty() {
+                    let tags = sio.input(0).tags();
+                    let pls = tags.iter().find_map(|t| match &t.tag {
+                        Tag::NamedUsize(name, pls) if t.index == consumed && name == PLS_TAG => {
+                            Some(*pls)
+                        }
+                        _ => None,
+                    });
+                    let frame_len = pls
+                        .and_then(|pls| u8::try_from(pls).ok())
+                        .and_then(|pls| Some((pls, plframe_len(pls)?)));
+                    match frame_len {
+                        Some((pls, frame_len)) => {
+                            self.pls = pls;
+                            self.misses = tags.iter().find_map(|t| match &t.tag {
+                                Tag::NamedUsize(name, n)
+                                    if t.index == consumed && name == PLSYNC_MISS_TAG =>
+                                {
+                                    Some(*n)
+                                }
+                                _ => None,
+                            });
+                            self.frame_len = frame_len;
+                        }
+                        None => {
+                            if let Some(pls) = pls {
+                                warn!("PlDescrambler: unsupported PLS code {pls}");
+                            }
+                            // Drop symbols up to the next tagged frame
+                            consumed = tags
+                                .iter()
+                                .filter(|t| {
+                                    t.index > consumed
+                                        && matches!(&t.tag, Tag::NamedUsize(name, _) if name == PLS_TAG)
+                                })
+                                .map(|t| t.index)
+                                .min()
+                                .unwrap_or(i.len())
+                                .min(i.len());
+                            continue;
+                        }
+                    }
+                }
+                let n = std::cmp::min(self.frame_len - self.plframe.len(), i.len() - consumed);
+                self.plframe.extend_from_slice(&i[consumed..consumed + n]);
+                consumed += n;
+            } else {
+                break;
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished =
+            sio.input(0).finished() && consumed == i.len() && self.data_pos == self.data.len();
+        Ok(())
+    }
+}
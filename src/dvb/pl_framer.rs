@@ -0,0 +1,269 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+use std::time::{Duration, Instant};
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_io::Timer;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::bit_interleaver::Interleaving;
+use super::config::{CodeRate, Constellation, Framesize, GoldCode, Modcod, GOLD_CODE_PERIOD};
+
+pub(super) const SLOT_SIZE: usize = 90;
+pub(super) const PL_HEADER_SIZE: usize = 90;
+pub(super) const PILOT_BLOCK_SIZE: usize = 36;
+pub(super) const PILOT_PERIOD_SLOTS: usize = 16;
+pub(super) const DUMMY_SLOTS: usize = 36;
+
+pub(super) const SOF_SIZE: usize = 26;
+pub(super) const SOF: u32 = 0x18D2E82;
+
+const PLS_GENERATOR: [u32; 6] = [
+    0x55555555, 0x33333333, 0x0F0F0F0F, 0x00FF00FF, 0x0000FFFF, 0xFFFFFFFF,
+];
+const PLS_SCRAMBLE: u64 = 0x719D83C953422DFA;
+
+pub(super) const PILOT: Complex32 = Complex32::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2);
+
+/// Number of PLFRAME symbols, including the PLHEADER, announced by the PLS code
+pub(super) fn plframe_len(pls: u8) -> Option<usize> {
+    let modcod = Modcod::try_from(pls).ok()?;
+    if matches!(modcod, Modcod::McDummy | Modcod::McDummyS) {
+        return Some(PL_HEADER_SIZE + DUMMY_SLOTS * SLOT_SIZE);
+    }
+    let slots =
+        modcod.framesize()?.frame_size() / modcod.constellation()?.bits_per_symbol() / SLOT_SIZE;
+    let pilot_len = if pls & 1 != 0 {
+        (slots - 1) / PILOT_PERIOD_SLOTS * PILOT_BLOCK_SIZE
+    } else {
+        0
+    };
+    Some(PL_HEADER_SIZE + slots * SLOT_SIZE + pilot_len)
+}
+
+/// Encodes the 7-bit PLS code (MODCOD, short frame flag and pilot flag, MSB first) with the
+/// (64, 7) biorthogonal code and scrambles it.
+pub(super) fn pls_code(pls: u8) -> u64 {
+    let y = PLS_GENERATOR
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| pls & (0x40 >> i) != 0)
+        .fold(0, |y, (_, g)| y ^ g);
+    let b6 = (pls & 1) as u64;
+    (0..32).fold(0u64, |code, m| {
+        let bit = ((y >> (31 - m)) & 1) as u64;
+        (code << 2) | (bit << 1) | (bit ^ b6)
+    }) ^ PLS_SCRAMBLE
+}
+
+/// Complex scrambling sequence `R_n(i)` for the symbols following the PLHEADER; each value is
+/// the number of π/2 rotations to apply.
+pub(super) fn pl_scrambling_sequence(gold_code: GoldCode, len: usize) -> Vec<u8> {
+    let period = GOLD_CODE_PERIOD as usize;
+    let mut x = vec![0u8; period];
+    x[0] = 1;
+    for i in 0..period - 18 {
+        x[i + 18] = x[i + 7] ^ x[i];
+    }
+    let mut y = vec![1u8; len + 131072];
+    for i in 0..y.len() - 18 {
+        y[i + 18] = y[i + 10] ^ y[i + 7] ^ y[i + 5] ^ y[i];
+    }
+    let n = gold_code.index() as usize;
+    let z = |i: usize| x[(i + n) % period] ^ y[i];
+    (0..len).map(|i| 2 * z(i + 131072) + z(i)).collect()
+}
+
+pub(super) fn rotate(s: Complex32, r: u8) -> Complex32 {
+    match r {
+        0 => s,
+        1 => Complex32::new(-s.im, s.re),
+        2 => -s,
+        _ => Complex32::new(s.im, -s.re),
+    }
+}
+
+/// π/2-BPSK mapping of the SOF and PLS code
+pub(super) fn pl_header(pls: u8) -> Vec<Complex32> {
+    let code = pls_code(pls);
+    (0..PL_HEADER_SIZE)
+        .map(|i| {
+            let bit = if i < SOF_SIZE {
+                (SOF >> (SOF_SIZE - 1 - i)) & 1
+            } else {
+                ((code >> (PL_HEADER_SIZE - 1 - i)) & 1) as u32
+            };
+            let a = if bit == 0 {
+                FRAC_1_SQRT_2
+            } else {
+                -FRAC_1_SQRT_2
+            };
+            if i % 2 == 0 {
+                Complex32::new(a, a)
+            } else {
+                Complex32::new(-a, a)
+            }
+        })
+        .collect()
+}
+
+/// Maps XFECFRAMEs to scrambled PLFRAMEs with their PLHEADER and, optionally, pilot blocks.
+///
+/// With a symbol rate for dummy frames, the framer keeps count of the symbols it has produced
+/// since it started. When no complete XFECFRAME is waiting and that count falls behind the
+/// symbols due at the given rate in real time, it emits a DUMMY PLFRAME. Without one, it never
+/// does, so the output only depends on the input.
+///
+/// Only the DVB-S2 MODCODs are framed: the PLHEADERs of the 8-bit DVB-S2X PLS codes, the VL-SNR
+/// headers of [`VlsnrHeader`](super::config::VlsnrHeader) and the extra pilots of the VL-SNR
+/// frames are not implemented.
+pub struct PhysicalLayerFramer {
+    header: Vec<Complex32>,
+    dummy_header: Vec<Complex32>,
+    pilots: bool,
+    /// Symbol rate below which the input underruns and dummy frames are emitted
+    dummy_rate: Option<f64>,
+    /// Start of the output and number of symbols produced since
+    start: Option<Instant>,
+    sent: u64,
+    scrambling: Vec<u8>,
+    /// Number of symbols per XFECFRAME
+    frame_len: usize,
+    xfecframe: Vec<Complex32>,
+    plframe: Vec<Complex32>,
+    /// Number of `plframe` symbols already produced
+    pos: usize,
+}
+
+impl PhysicalLayerFramer {
+    pub fn new(
+        framesize: Framesize,
+        rate: CodeRate,
+        constellation: Constellation,
+        pilots: bool,
+        dummy_rate: Option<f64>,
+        gold_code: GoldCode,
+    ) -> Option<Block> {
+        let interleaving = Interleaving::dvb(framesize, rate, constellation)?;
+        let pls = Modcod::from_parts(constellation, rate, framesize, pilots)?.pls(pilots);
+        if pls & 0x80 != 0 {
+            return None;
+        }
+        let frame_len = interleaving.n / interleaving.bits;
+        let scrambling_len =
+            std::cmp::max(plframe_len(pls)? - PL_HEADER_SIZE, DUMMY_SLOTS * SLOT_SIZE);
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_PL_Framer").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                header: pl_header(pls),
+                dummy_header: pl_header((Modcod::McDummy as u8) << 1),
+                pilots,
+                dummy_rate,
+                start: None,
+                sent: 0,
+                scrambling: pl_scrambling_sequence(gold_code, scrambling_len),
+                frame_len,
+                xfecframe: Vec::with_capacity(frame_len),
+                plframe: Vec::new(),
+                pos: 0,
+            },
+        ))
+    }
+
+    fn build_frame(&mut self) {
+        self.plframe.clear();
+        self.plframe.extend_from_slice(&self.header);
+        for (i, slot) in self.xfecframe.chunks(SLOT_SIZE).enumerate() {
+            if self.pilots && i > 0 && i % PILOT_PERIOD_SLOTS == 0 {
+                self.plframe.extend([PILOT; PILOT_BLOCK_SIZE]);
+            }
+            self.plframe.extend_from_slice(slot);
+        }
+        self.xfecframe.clear();
+        self.scramble();
+    }
+
+    fn build_dummy_frame(&mut self) {
+        self.plframe.clear();
+        self.plframe.extend_from_slice(&self.dummy_header);
+        self.plframe.extend([PILOT; DUMMY_SLOTS * SLOT_SIZE]);
+        self.scramble();
+    }
+
+    fn scramble(&mut self) {
+        for (s, &r) in self.plframe[PL_HEADER_SIZE..]
+            .iter_mut()
+            .zip(&self.scrambling)
+        {
+            *s = rotate(*s, r);
+        }
+        self.pos = 0;
+    }
+}
+
+#[async_trait]
+impl Kernel for PhysicalLayerFramer {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let mut consumed = 0;
+        let mut produced = 0;
+        let start = *self.start.get_or_insert_with(Instant::now);
+
+        loop {
+            if self.pos < self.plframe.len() {
+                let n = std::cmp::min(self.plframe.len() - self.pos, o.len() - produced);
+                if n == 0 {
+                    break;
+                }
+                o[produced..produced + n].copy_from_slice(&self.plframe[self.pos..self.pos + n]);
+                self.pos += n;
+                produced += n;
+            } else if self.xfecframe.len() == self.frame_len {
+                self.build_frame();
+            } else if consumed < i.len() {
+                let n = std::cmp::min(self.frame_len - self.xfecframe.len(), i.len() - consumed);
+                self.xfecframe.extend_from_slice(&i[consumed..consumed + n]);
+                consumed += n;
+            } else if let Some(rate) = self.dummy_rate.filter(|_| !sio.input(0).finished()) {
+                // Symbols are due until the output runs dry at the given rate
+                let due =
+                    start + Duration::from_secs_f64((self.sent + produced as u64) as f64 / rate);
+                if Instant::now() < due {
+                    io.block_on(async move {
+                        Timer::at(due).await;
+                    });
+                    break;
+                }
+                self.build_dummy_frame();
+            } else {
+                break;
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+        self.sent += produced as u64;
+
+        io.finished = sio.input(0).finished()
+            && consumed == i.len()
+            && self.pos == self.plframe.len()
+            && self.xfecframe.len() < self.frame_len;
+        Ok(())
+    }
+}
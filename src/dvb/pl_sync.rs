@@ -0,0 +1,345 @@
+use std::collections::VecDeque;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::config::{
+    Framesize, Modcod, MODCOD_TAG, PILOTS_TAG, PLSYNC_MISS_TAG, PLS_TAG, SHORT_FRAME_TAG,
+};
+use super::pl_framer::{pl_header, plframe_len, PL_HEADER_SIZE, SOF_SIZE};
+
+/// Normalized correlation needed to detect a PLHEADER while searching
+const SEARCH_THRESHOLD: f32 = 0.4;
+/// Normalized correlation needed to confirm a PLHEADER where the previous one announced it
+/// while locked
+const TRACK_THRESHOLD: f32 = 0.25;
+/// Number of consecutive expected PLHEADERs that may be missed before the lock is dropped
+const MAX_MISSES: usize = 3;
+/// Spacing of the frequencies in rad/symbol tried around the differential estimate when
+/// decoding the PLSC
+const FREQ_STEP: f32 = 0.02;
+/// Number of frequencies tried on either side of the differential estimate
+const FREQ_STEPS: i32 = 25;
+
+enum State {
+    /// Sliding over the input looking for PLHEADERs
+    Search,
+    /// Collecting the PLHEADER expected after the current frame
+    Header,
+    /// Passing on the symbols of a PLFRAME after its header
+    Frame { remaining: usize },
+}
+
+/// PLHEADER position announced by a header found while searching
+struct Candidate {
+    start: usize,
+    /// Number of consecutive headers that led to this one
+    count: usize,
+}
+
+/// Frame synchronizer on symbols.
+///
+/// PLHEADERs are detected by differential correlation with the SOF and the pairwise structure
+/// of the PLSC, which is insensitive to frequency offsets. The PLSC is decoded by maximum
+/// likelihood over the valid PLS codes, correlating the whole PLHEADER non-coherently on a grid
+/// of frequencies around the differential estimate.
+///
+/// While searching, every detected header is a candidate and the synchronizer locks once
+/// `lock_count` consecutive headers were found where their predecessor announced them. When
+/// locked, complete PLFRAMEs are output. Their first symbol is tagged with the PLS code and with
+/// its decoded MODCOD, pilot flag and short frame flag. A missed
+/// header reuses the PLS code of the previous frame and the frame is additionally tagged as missed,
+/// so that the synchronization loops skip it. The lock is dropped after a few misses in a row. Expected
+/// headers are also looked for one symbol earlier and later, which follows slips of the timing
+/// recovery.
+///
+/// Only the 7-bit DVB-S2 PLS codes are searched for, like the
+/// [`PhysicalLayerFramer`](super::PhysicalLayerFramer), which does not code the 8-bit DVB-S2X
+/// ones either.
+pub struct PlSync {
+    /// Differential SOF reference
+    sof: Vec<Complex32>,
+    /// Differential reference of the PLSC symbol pairs without pilots
+    plsc: Vec<Complex32>,
+    /// SOF symbols
+    sof_symbols: Vec<Complex32>,
+    /// Valid PLS codes with their PLSC symbols
+    codes: Vec<(u8, Vec<Complex32>)>,
+    lock_count: usize,
+    candidates: Vec<Candidate>,
+    /// Number of input symbols consumed
+    position: usize,
+    state: State,
+    /// Symbols around the PLHEADER, followed by the first symbols of the frame once it started
+    header: VecDeque<Complex32>,
+    pls: u8,
+    /// Number of expected PLHEADERs missed in a row
+    misses: usize,
+    /// Number of `header` symbols already output for the current frame
+    header_out: usize,
+}
+
+impl PlSync {
+    pub fn new(lock_count: usize) -> Block {
+        let h = pl_header(0);
+        let sof = (1..SOF_SIZE).map(|i| h[i] * h[i - 1].conj()).collect();
+        let plsc = (SOF_SIZE..PL_HEADER_SIZE)
+            .step_by(2)
+            .map(|i| h[i + 1] * h[i].conj())
+            .collect();
+        let codes = (0..128)
+            .filter(|&pls| plframe_len(pls).is_some())
+            .map(|pls| (pls, pl_header(pls)[SOF_SIZE..].to_vec()))
+            .collect();
+        Block::new(
+            BlockMetaBuilder::new("DVB_PL_Sync").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                sof,
+                plsc,
+                sof_symbols: h[..SOF_SIZE].to_vec(),
+                codes,
+                lock_count,
+                candidates: Vec::new(),
+                position: 0,
+                state: State::Search,
+                header: VecDeque::with_capacity(PL_HEADER_SIZE + 2),
+                pls: 0,
+                misses: 0,
+                header_out: 0,
+            },
+        )
+    }
+
+    /// Decodes the PLS code and returns it with the normalized correlation if `y` holds a
+    /// PLHEADER with a normalized correlation of at least `threshold`.
+    fn detect(&self, y: &[Complex32], threshold: f32) -> Option<(u8, f32)> {
+        let d = |i: usize| y[i] * y[i - 1].conj();
+
+        let mut energy = 0.0;
+        let mut c_sof = Complex32::new(0.0, 0.0);
+        for (i, r) in (1..SOF_SIZE).zip(&self.sof) {
+            let d = d(i);
+            energy += d.norm();
+            c_sof += d * r.conj();
+        }
+        let mut c_plsc = Complex32::new(0.0, 0.0);
+        for (i, r) in (SOF_SIZE + 1..PL_HEADER_SIZE).step_by(2).zip(&self.plsc) {
+            let d = d(i);
+            energy += d.norm();
+            c_plsc += d * r.conj();
+        }
+        let c = if (c_sof + c_plsc).norm() > (c_sof - c_plsc).norm() {
+            c_sof + c_plsc
+        } else {
+            c_sof - c_plsc
+        };
+        if energy == 0.0 || c.norm() / energy < threshold {
+            return None;
+        }
+        let correlation = c.norm() / energy;
+
+        let w = c.arg();
+        let mut best = None;
+        let mut best_metric = 0.0;
+        let mut r = vec![Complex32::new(0.0, 0.0); PL_HEADER_SIZE];
+        for k in -FREQ_STEPS..=FREQ_STEPS {
+            let f = w + k as f32 * FREQ_STEP;
+            for (i, (r, y)) in r.iter_mut().zip(y.iter()).enumerate() {
+                *r = y * Complex32::from_polar(1.0, -f * i as f32);
+            }
+            let c_sof: Complex32 = r[..SOF_SIZE]
+                .iter()
+                .zip(&self.sof_symbols)
+                .map(|(r, h)| r * h.conj())
+                .sum();
+            for (pls, h) in &self.codes {
+                let c_plsc: Complex32 =
+                    r[SOF_SIZE..].iter().zip(h).map(|(r, h)| r * h.conj()).sum();
+                let metric = (c_sof + c_plsc).norm_sqr();
+                if metric > best_metric {
+                    best = Some(*pls);
+                    best_metric = metric;
+                }
+            }
+        }
+        best.map(|pls| (pls, correlation))
+    }
+
+    /// Starts a frame whose PLHEADER begins at `offset` in `header`.
+    fn start_frame(&mut self, pls: u8, offset: usize) {
+        self.header.drain(..offset);
+        self.pls = pls;
+        self.header_out = 0;
+        self.state = State::Frame {
+            remaining: plframe_len(pls).unwrap() - self.header.len(),
+        };
+    }
+}
+
+#[async_trait]
+impl Kernel for PlSync {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            match self.state {
+                State::Search => {
+                    if consumed == i.len() {
+                        break;
+                    }
+                    self.header.push_back(i[consumed]);
+                    consumed += 1;
+                    self.position += 1;
+                    if self.header.len() > PL_HEADER_SIZE {
+                        self.header.pop_front();
+                    }
+                    if self.header.len() < PL_HEADER_SIZE {
+                        continue;
+                    }
+
+                    let start = self.position - PL_HEADER_SIZE;
+                    let expected = self.candidates.iter().position(|c| c.start == start);
+                    let count = expected.map(|k| self.candidates.swap_remove(k).count + 1);
+                    self.header.make_contiguous();
+                    let detected = self.detect(self.header.as_slices().0, SEARCH_THRESHOLD);
+                    if let Some((pls, _)) = detected {
+                        let count = count.unwrap_or(0);
+                        if count >= self.lock_count {
+                            self.candidates.clear();
+                            self.start_frame(pls, 0);
+                        } else {
+                            self.candidates.push(Candidate {
+                                start: start + plframe_len(pls).unwrap(),
+                                count,
+                            });
+                        }
+                    }
+                }
+                State::Header => {
+                    // The last symbol of the previous frame, the PLHEADER and one more symbol
+                    let len = PL_HEADER_SIZE + 2;
+                    let n = std::cmp::min(len - self.header.len(), i.len() - consumed);
+                    self.header.extend(&i[consumed..consumed + n]);
+                    consumed += n;
+                    self.position += n;
+                    if self.header.len() < len {
+                        break;
+                    }
+                    self.header.make_contiguous();
+                    let y = self.header.as_slices().0;
+                    let best = (0..=2)
+                        .filter_map(|offset| {
+                            let y = &y[offset..offset + PL_HEADER_SIZE];
+                            Some((offset, self.detect(y, TRACK_THRESHOLD)?))
+                        })
+                        .max_by(|a, b| a.1 .1.total_cmp(&b.1 .1));
+                    match best {
+                        Some((offset, (pls, _))) => {
+                            self.misses = 0;
+                            self.start_frame(pls, offset);
+                        }
+                        None if self.misses < MAX_MISSES => {
+                            self.misses += 1;
+                            self.start_frame(self.pls, 1);
+                        }
+                        None => {
+                            self.misses = 0;
+                            self.header.drain(..len - PL_HEADER_SIZE);
+                            self.state = State::Search;
+                        }
+                    }
+                }
+                State::Frame { remaining } => {
+                    if self.header_out < self.header.len() {
+                        let n =
+                            std::cmp::min(self.header.len() - self.header_out, o.len() - produced);
+                        if n == 0 {
+                            break;
+                        }
+                        if self.header_out == 0 {
+                            sio.output(0).add_tag(
+                                produced,
+                                Tag::NamedUsize(PLS_TAG.to_string(), self.pls as usize),
+                            );
+                            if let Ok(modcod) = Modcod::try_from(self.pls) {
+                                let short = modcod.framesize() == Some(Framesize::Short);
+                                let tags = [
+                                    Tag::NamedAny(MODCOD_TAG.to_string(), Box::new(modcod)),
+                                    Tag::NamedUsize(
+                                        PILOTS_TAG.to_string(),
+                                        (self.pls & 1) as usize,
+                                    ),
+                                    Tag::NamedUsize(SHORT_FRAME_TAG.to_string(), short as usize),
+                                ];
+                                for tag in tags {
+                                    sio.output(0).add_tag(produced, tag);
+                                }
+                            }
+                            if self.misses > 0 {
+                                sio.output(0).add_tag(
+                                    produced,
+                                    Tag::NamedUsize(PLSYNC_MISS_TAG.to_string(), self.misses),
+                                );
+                            }
+                        }
+                        for (o, h) in o[produced..produced + n]
+                            .iter_mut()
+                            .zip(self.header.range(self.header_out..))
+                        {
+                            *o = *h;
+                        }
+                        self.header_out += n;
+                        produced += n;
+                        continue;
+                    }
+
+                    let n = std::cmp::min(remaining, i.len() - consumed);
+                    let n = std::cmp::min(n, o.len() - produced);
+                    if n == 0 {
+                        break;
+                    }
+                    o[produced..produced + n].copy_from_slice(&i[consumed..consumed + n]);
+                    produced += n;
+                    consumed += n;
+                    self.position += n;
+                    if n == remaining {
+                        self.header.clear();
+                        self.header.push_back(i[consumed - 1]);
+                        self.state = State::Header;
+                    } else {
+                        self.state = State::Frame {
+                            remaining: remaining - n,
+                        };
+                    }
+                }
+            }
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        let flushing =
+            matches!(self.state, State::Frame { .. }) && self.header_out < self.header.len();
+        io.finished = sio.input(0).finished() && consumed == i.len() && !flushing;
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::config::{CodeRate, Constellation};
+use super::modulator::constellation_points;
+
+/// Number of feedback symbols per update of the adaptive predistorter
+const UPDATE_LEN: usize = 4096;
+
+/// Complex correction factors per APSK ring, innermost ring first
+pub type PredistortionTable = HashMap<(Constellation, CodeRate), Vec<Complex32>>;
+
+/// State of the adaptive mode
+struct Adaptation {
+    step: f32,
+    /// Feedback symbols of the current update
+    symbols: Vec<Complex32>,
+}
+
+/// Predistortion of the ring radii and phases of APSK symbols from the
+/// [`Modulator`](super::Modulator), to compensate the AM/AM and AM/PM of the amplifier.
+///
+/// Every symbol is multiplied with the correction factor of its ring. The static predistorter
+/// takes the factors for its MODCOD from a [`PredistortionTable`]. The adaptive predistorter
+/// additionally learns them from received XFECFRAME symbols on its `feedback` input, such as
+/// the `out` port of a [`PlDescrambler`](super::PlDescrambler) behind the amplifier. The
+/// feedback symbols do not have to be aligned with the transmitted ones, they are assigned to
+/// rings by hard decisions. The outermost ring keeps its factor and the inner rings are adjusted
+/// with gain `step` such that their complex gain relative to it is one. As the feedback lags
+/// behind by the buffers of the transmit and receive chains, `step` should stay at a few percent.
+/// The factors are posted on the `corrections` message port after each update, as real and
+/// imaginary parts in one vector.
+pub struct Predistorter {
+    /// Ring radii in increasing order
+    radii: Vec<f32>,
+    corrections: Vec<Complex32>,
+    points: Vec<Complex32>,
+    adaptation: Option<Adaptation>,
+}
+
+impl Predistorter {
+    pub fn new(
+        rate: CodeRate,
+        constellation: Constellation,
+        table: &PredistortionTable,
+    ) -> Option<Block> {
+        let corrections = table.get(&(constellation, rate))?.clone();
+        Self::build(rate, constellation, corrections, None)
+    }
+
+    /// Adaptive predistorter, starting from the factors in `table` or without correction if the
+    /// table has no entry for the MODCOD
+    pub fn adaptive(
+        rate: CodeRate,
+        constellation: Constellation,
+        table: &PredistortionTable,
+        step: f32,
+    ) -> Option<Block> {
+        if !(step > 0.0 && step <= 1.0) {
+            return None;
+        }
+        let corrections = match table.get(&(constellation, rate)) {
+            Some(corrections) => corrections.clone(),
+            None => vec![Complex32::new(1.0, 0.0); Self::rings(rate, constellation)?.len()],
+        };
+        let adaptation = Adaptation {
+            step,
+            symbols: Vec::with_capacity(UPDATE_LEN),
+        };
+        Self::build(rate, constellation, corrections, Some(adaptation))
+    }
+
+    /// Ring radii of an APSK constellation in increasing order
+    fn rings(rate: CodeRate, constellation: Constellation) -> Option<Vec<f32>> {
+        let mut radii: Vec<f32> = Vec::new();
+        for p in constellation_points(rate, constellation)? {
+            let r = p.norm();
+            if radii.iter().all(|&x| (x - r).abs() > 1e-3) {
+                radii.push(r);
+            }
+        }
+        radii.sort_by(f32::total_cmp);
+        (radii.len() > 1).then_some(radii)
+    }
+
+    fn build(
+        rate: CodeRate,
+        constellation: Constellation,
+        corrections: Vec<Complex32>,
+        adaptation: Option<Adaptation>,
+    ) -> Option<Block> {
+        let radii = Self::rings(rate, constellation)?;
+        if corrections.len() != radii.len() {
+            return None;
+        }
+        let mut sio = StreamIoBuilder::new()
+            .add_input::<Complex32>("in")
+            .add_output::<Complex32>("out");
+        if adaptation.is_some() {
+            sio = sio.add_input::<Complex32>("feedback");
+        }
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Predistorter").build(),
+            sio.build(),
+            MessageIoBuilder::<Self>::new()
+                .add_output("corrections")
+                .build(),
+            Self {
+                radii,
+                corrections,
+                points: constellation_points(rate, constellation)?,
+                adaptation,
+            },
+        ))
+    }
+
+    fn ring(&self, r: f32) -> usize {
+        (0..self.radii.len())
+            .min_by(|&a, &b| {
+                (self.radii[a] - r)
+                    .abs()
+                    .total_cmp(&(self.radii[b] - r).abs())
+            })
+            .unwrap()
+    }
+
+    /// Updates the corrections from a full block of feedback symbols.
+    fn adapt(&mut self) {
+        let Some(adaptation) = &mut self.adaptation else {
+            return;
+        };
+        let symbols = std::mem::take(&mut adaptation.symbols);
+        let step = adaptation.step;
+        let power = symbols.iter().map(|y| y.norm_sqr()).sum::<f32>() / symbols.len() as f32;
+        if power == 0.0 {
+            return;
+        }
+
+        // Complex gain of each ring
+        let mut gains = vec![(Complex32::new(0.0, 0.0), 0); self.radii.len()];
+        for y in symbols.iter().map(|y| y / power.sqrt()) {
+            let decision = self
+                .points
+                .iter()
+                .min_by(|a, b| (y - *a).norm_sqr().total_cmp(&(y - *b).norm_sqr()))
+                .unwrap();
+            let g = &mut gains[self.ring(decision.norm())];
+            g.0 += y / decision;
+            g.1 += 1;
+        }
+
+        let outer = self.radii.len() - 1;
+        if gains[outer].1 == 0 {
+            return;
+        }
+        let reference = gains[outer].0 / gains[outer].1 as f32;
+        for (c, &(sum, count)) in self.corrections[..outer].iter_mut().zip(&gains) {
+            if count > 0 {
+                let gain = sum / count as f32;
+                *c *= Complex32::new(1.0, 0.0) + (reference / gain - 1.0) * step;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Kernel for Predistorter {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let mut updates = 0;
+        if self.adaptation.is_some() {
+            let f = sio.input(1).slice::<Complex32>();
+            let mut consumed = 0;
+            while consumed < f.len() {
+                let adaptation = self.adaptation.as_mut().unwrap();
+                let n = std::cmp::min(UPDATE_LEN - adaptation.symbols.len(), f.len() - consumed);
+                adaptation
+                    .symbols
+                    .extend_from_slice(&f[consumed..consumed + n]);
+                consumed += n;
+                if adaptation.symbols.len() == UPDATE_LEN {
+                    self.adapt();
+                    updates += 1;
+                }
+            }
+            sio.input(1).consume(consumed);
+        }
+
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+
+        let m = std::cmp::min(i.len(), o.len());
+        for (x, y) in i[..m].iter().zip(o[..m].iter_mut()) {
+            *y = x * self.corrections[self.ring(x.norm())];
+        }
+
+        sio.input(0).consume(m);
+        sio.output(0).produce(m);
+
+        if updates > 0 {
+            let corrections = self.corrections.iter().flat_map(|c| [c.re, c.im]).collect();
+            mio.post(0, Pmt::VecF32(corrections)).await;
+        }
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
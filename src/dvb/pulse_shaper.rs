@@ -0,0 +1,97 @@
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::config::RolloffFactor;
+
+/// Root-raised-cosine taps spanning `span` symbols at `sps` samples per symbol, scaled so that
+/// interpolated unit-power symbols keep unit power.
+pub(super) fn rrc_taps(rolloff: f32, sps: usize, span: usize) -> Vec<f32> {
+    let b = rolloff;
+    let n = span * sps + 1;
+    let taps: Vec<f32> = (0..n)
+        .map(|i| {
+            let t = (i as f32 - (n - 1) as f32 / 2.0) / sps as f32;
+            if t == 0.0 {
+                1.0 - b + 4.0 * b / PI
+            } else if ((4.0 * b * t).abs() - 1.0).abs() < 1e-3 {
+                // t = ±1/(4b) only up to rounding, e.g. for a rolloff of 0.15 at 3 samples per symbol
+                b * FRAC_1_SQRT_2
+                    * ((1.0 + 2.0 / PI) * (PI / (4.0 * b)).sin()
+                        + (1.0 - 2.0 / PI) * (PI / (4.0 * b)).cos())
+            } else {
+                ((PI * t * (1.0 - b)).sin() + 4.0 * b * t * (PI * t * (1.0 + b)).cos())
+                    / (PI * t * (1.0 - (4.0 * b * t).powi(2)))
+            }
+        })
+        .collect();
+    let gain = (sps as f32 / taps.iter().map(|h| h * h).sum::<f32>()).sqrt();
+    taps.into_iter().map(|h| h * gain).collect()
+}
+
+pub struct PulseShaper {
+    /// Filter taps split into `sps` phases
+    phases: Vec<Vec<f32>>,
+    /// Most recent symbol first
+    history: Vec<Complex32>,
+}
+
+impl PulseShaper {
+    pub fn new(rolloff: RolloffFactor, sps: usize, span: usize) -> Option<Block> {
+        if sps == 0 || span == 0 {
+            return None;
+        }
+        let taps = rrc_taps(rolloff.value()?, sps, span);
+        let phases: Vec<Vec<f32>> = (0..sps)
+            .map(|p| taps.iter().skip(p).step_by(sps).copied().collect())
+            .collect();
+        let history = vec![Complex32::new(0.0, 0.0); phases[0].len()];
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Pulse_Shaper").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self { phases, history },
+        ))
+    }
+}
+
+#[async_trait]
+impl Kernel for PulseShaper {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let sps = self.phases.len();
+
+        let m = std::cmp::min(i.len(), o.len() / sps);
+        if m > 0 {
+            for (&s, r) in i.iter().zip(o.chunks_exact_mut(sps)) {
+                self.history.rotate_right(1);
+                self.history[0] = s;
+                for (y, phase) in r.iter_mut().zip(&self.phases) {
+                    *y = phase.iter().zip(&self.history).map(|(h, x)| x * h).sum();
+                }
+            }
+
+            sio.input(0).consume(m);
+            sio.output(0).produce(m * sps);
+        }
+
+        io.finished = sio.input(0).finished() && m == i.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use futuresdr::anyhow::{anyhow, Result};
+use futuresdr::async_trait::async_trait;
+use futuresdr::macros::message_handler;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Flowgraph, Kernel, MessageIo, MessageIoBuilder, Pmt,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::config::{CodeRate, Constellation, Framesize, GoldCode, RolloffFactor};
+use super::{
+    Agc, BbDeframer, BbDescrambler, BchDecoder, BitDeinterleaver, Demapping, FrequencySync,
+    LdpcDecoder, MinSum, PhaseTracker, PlDescrambler, PlSync, SoftDemapper, SymbolSync,
+};
+
+/// Adaptation rate per sample of the AGC
+const AGC_RATE: f32 = 1e-4;
+/// Loop bandwidth of the timing recovery, normalized to the symbol rate
+const TIMING_BANDWIDTH: f32 = 0.002;
+const TIMING_DAMPING: f32 = std::f32::consts::FRAC_1_SQRT_2;
+/// Number of consecutive PLHEADERs required for frame lock
+const LOCK_COUNT: usize = 2;
+/// Gain of the frequency loop, updated once per PLFRAME
+const FREQUENCY_GAIN: f32 = 0.2;
+
+/// Collects the state of the receive chain into one map.
+struct Status {
+    status: HashMap<String, Pmt>,
+}
+
+impl Status {
+    fn new() -> Block {
+        Block::new(
+            BlockMetaBuilder::new("DVB_Receiver_Status").build(),
+            StreamIoBuilder::new().build(),
+            MessageIoBuilder::<Self>::new()
+                .add_input("frequency", Self::frequency)
+                .add_input("uncorrectable", Self::uncorrectable)
+                .add_input("crc_errors", Self::crc_errors)
+                .add_output("status")
+                .build(),
+            Self {
+                status: HashMap::from([
+                    ("frequency".to_string(), Pmt::F32(0.0)),
+                    ("uncorrectable".to_string(), Pmt::Usize(0)),
+                    ("header_errors".to_string(), Pmt::Usize(0)),
+                    ("packet_errors".to_string(), Pmt::Usize(0)),
+                ]),
+            },
+        )
+    }
+
+    async fn update(
+        &mut self,
+        io: &mut WorkIo,
+        mio: &mut MessageIo<Self>,
+        values: Vec<(&str, Pmt)>,
+    ) -> Result<Pmt> {
+        if values.is_empty() {
+            io.finished = mio.inputs().iter().all(|i| i.finished());
+            return Ok(Pmt::Ok);
+        }
+        for (key, value) in values {
+            self.status.insert(key.to_string(), value);
+        }
+        mio.post(0, Pmt::MapStrPmt(self.status.clone())).await;
+        Ok(Pmt::Ok)
+    }
+
+    #[message_handler]
+    async fn frequency(
+        &mut self,
+        io: &mut WorkIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+        p: Pmt,
+    ) -> Result<Pmt> {
+        let values = match p {
+            Pmt::F32(_) => vec![("frequency", p)],
+            Pmt::Finished => vec![],
+            _ => return Ok(Pmt::InvalidValue),
+        };
+        self.update(io, mio, values).await
+    }
+
+    #[message_handler]
+    async fn uncorrectable(
+        &mut self,
+        io: &mut WorkIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+        p: Pmt,
+    ) -> Result<Pmt> {
+        let values = match p {
+            Pmt::Usize(_) => vec![("uncorrectable", p)],
+            Pmt::Finished => vec![],
+            _ => return Ok(Pmt::InvalidValue),
+        };
+        self.update(io, mio, values).await
+    }
+
+    #[message_handler]
+    async fn crc_errors(
+        &mut self,
+        io: &mut WorkIo,
+        mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+        p: Pmt,
+    ) -> Result<Pmt> {
+        let values = match p {
+            Pmt::MapStrPmt(mut counts) => {
+                match (counts.remove("header"), counts.remove("packet")) {
+                    (Some(header), Some(packet)) => {
+                        vec![("header_errors", header), ("packet_errors", packet)]
+                    }
+                    _ => return Ok(Pmt::InvalidValue),
+                }
+            }
+            Pmt::Finished => vec![],
+            _ => return Ok(Pmt::InvalidValue),
+        };
+        self.update(io, mio, values).await
+    }
+}
+
+#[async_trait]
+impl Kernel for Status {}
+
+/// Builder for the complete DVB-S2 receive chain of a CCM stream, from complex samples to
+/// transport stream bytes.
+///
+/// The samples are expected at `sps` samples per symbol with the pulse shape of a
+/// [`Transmitter`](super::Transmitter) with the same rolloff. Frames of other MODCODs are not
+/// decoded.
+#[derive(Clone, Copy)]
+pub struct Receiver {
+    framesize: Framesize,
+    rate: CodeRate,
+    constellation: Constellation,
+    rolloff: RolloffFactor,
+    gold_code: GoldCode,
+    sps: usize,
+    span: usize,
+    demapping: Demapping,
+    esn0: f32,
+    ldpc_iterations: usize,
+    min_sum: MinSum,
+    decision_directed: bool,
+}
+
+impl Receiver {
+    pub fn new(framesize: Framesize, rate: CodeRate, constellation: Constellation) -> Self {
+        Self {
+            framesize,
+            rate,
+            constellation,
+            rolloff: RolloffFactor::Ro0_20,
+            gold_code: GoldCode::default(),
+            sps: 2,
+            span: 16,
+            demapping: Demapping::LogMap,
+            esn0: 10.0,
+            ldpc_iterations: 50,
+            min_sum: MinSum::default(),
+            decision_directed: false,
+        }
+    }
+
+    pub fn rolloff(mut self, rolloff: RolloffFactor) -> Self {
+        self.rolloff = rolloff;
+        self
+    }
+
+    pub fn gold_code(mut self, gold_code: GoldCode) -> Self {
+        self.gold_code = gold_code;
+        self
+    }
+
+    /// Samples per symbol and filter span in symbols of the matched filter
+    pub fn shaping(mut self, sps: usize, span: usize) -> Self {
+        self.sps = sps;
+        self.span = span;
+        self
+    }
+
+    pub fn demapping(mut self, demapping: Demapping) -> Self {
+        self.demapping = demapping;
+        self
+    }
+
+    /// Es/N0 in dB assumed for the LLRs
+    pub fn esn0(mut self, esn0: f32) -> Self {
+        self.esn0 = esn0;
+        self
+    }
+
+    pub fn ldpc_iterations(mut self, ldpc_iterations: usize) -> Self {
+        self.ldpc_iterations = ldpc_iterations;
+        self
+    }
+
+    /// Check node correction of the LDPC decoder
+    pub fn min_sum(mut self, min_sum: MinSum) -> Self {
+        self.min_sum = min_sum;
+        self
+    }
+
+    /// Enables the decision-directed phase tracking between the pilots of the
+    /// [`PhaseTracker`](super::PhaseTracker)
+    pub fn decision_directed(mut self, decision_directed: bool) -> Self {
+        self.decision_directed = decision_directed;
+        self
+    }
+
+    fn blocks(&self) -> Option<Vec<Block>> {
+        let (framesize, rate, constellation) = (self.framesize, self.rate, self.constellation);
+        Some(vec![
+            Agc::new(AGC_RATE)?,
+            SymbolSync::new(
+                self.rolloff,
+                self.sps,
+                self.span,
+                TIMING_BANDWIDTH,
+                TIMING_DAMPING,
+            )?,
+            PlSync::new(LOCK_COUNT),
+            FrequencySync::new(self.gold_code, FREQUENCY_GAIN)?,
+            PhaseTracker::new(self.gold_code, self.decision_directed),
+            PlDescrambler::new(self.gold_code),
+            SoftDemapper::new(
+                framesize,
+                rate,
+                constellation,
+                self.demapping,
+                10f32.powf(-self.esn0 / 10.0),
+            )?,
+            BitDeinterleaver::new(framesize, rate, constellation)?,
+            LdpcDecoder::<f32>::new(framesize, rate, self.ldpc_iterations, self.min_sum)?,
+            BchDecoder::new(framesize, rate)?,
+            BbDescrambler::new(framesize, rate)?,
+            BbDeframer::new(framesize, rate)?,
+        ])
+    }
+
+    /// Adds the receive chain to `fg` and returns the ids of its first block, its last block and
+    /// its status block.
+    ///
+    /// The first block takes `Complex32` samples on its `in` port, the last one produces `u8`
+    /// transport stream bytes on its `out` port. The status block posts a map with the
+    /// `frequency` offset in cycles per symbol, the number of `uncorrectable` BCH codewords and
+    /// the numbers of BBHEADER `header_errors` and TS `packet_errors` on its `status` message
+    /// port whenever one of them changes.
+    pub fn add(&self, fg: &mut Flowgraph) -> Result<(usize, usize, usize)> {
+        let blocks = self
+            .blocks()
+            .ok_or_else(|| anyhow!("unsupported DVB-S2 receiver configuration"))?;
+        let ids: Vec<usize> = blocks.into_iter().map(|b| fg.add_block(b)).collect();
+        for w in ids.windows(2) {
+            fg.connect_stream(w[0], "out", w[1], "in")?;
+        }
+        let [agc, _, _, frequency_sync, _, _, _, _, _, bch_decoder, _, bb_deframer] = ids[..]
+        else {
+            unreachable!()
+        };
+        let status = fg.add_block(Status::new());
+        fg.connect_message(frequency_sync, "frequency", status, "frequency")?;
+        fg.connect_message(bch_decoder, "uncorrectable", status, "uncorrectable")?;
+        fg.connect_message(bb_deframer, "crc_errors", status, "crc_errors")?;
+        Ok((agc, bb_deframer, status))
+    }
+
+    /// Adds the receive chain to `fg`, feeds it from port `port` of block `src` and returns the
+    /// ids of the block producing the transport stream on its `out` port and of the status block.
+    pub fn connect(&self, fg: &mut Flowgraph, src: usize, port: &str) -> Result<(usize, usize)> {
+        let (input, output, status) = self.add(fg)?;
+        fg.connect_stream(src, port, input, "in")?;
+        Ok((output, status))
+    }
+}
@@ -0,0 +1,242 @@
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::log::warn;
+use futuresdr::macros::message_handler;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, Pmt, StreamIo,
+    StreamIoBuilder, Tag, WorkIo,
+};
+
+use super::bit_interleaver::Interleaving;
+use super::config::{pls_matches, CodeRate, Constellation, Framesize, PLS_TAG};
+use super::modulator::constellation_points;
+
+#[derive(Clone, Copy)]
+pub enum Demapping {
+    /// Exact log-MAP
+    LogMap,
+    /// Max-log approximation
+    MaxLog,
+}
+
+/// `log(exp(a) + exp(b))`
+fn max_star(a: f32, b: f32) -> f32 {
+    if a == f32::NEG_INFINITY {
+        b
+    } else if b == f32::NEG_INFINITY {
+        a
+    } else {
+        a.max(b) + (-(a - b).abs()).exp().ln_1p()
+    }
+}
+
+/// Converts received symbols of an XFECFRAME into LLRs `log(P(0) / P(1))`, one per bit in the
+/// order in which the bits were mapped, i.e. the order the bit deinterleaver expects.
+///
+/// The noise variance is `E|n|^2` for unit-power symbols. It can be updated with the Es/N0 in dB
+/// on the `snr` message port.
+///
+/// A PLS tag, as output by the [`PlDescrambler`](super::PlDescrambler), marks the first symbol of
+/// an XFECFRAME and is forwarded to its first LLR. Frames tagged with another MODCOD are dropped.
+pub struct SoftDemapper {
+    framesize: Framesize,
+    rate: CodeRate,
+    constellation: Constellation,
+    points: Vec<Complex32>,
+    bits: usize,
+    demapping: Demapping,
+    noise_variance: f32,
+    /// π/2-BPSK rotates every odd symbol of the XFECFRAME
+    pi2: bool,
+    /// BPSK-SF2 maps every bit onto two consecutive symbols
+    spread: usize,
+    /// Number of input symbols per XFECFRAME
+    frame_len: usize,
+    index: usize,
+    /// Whether the symbols up to the next PLS tag belong to a dropped frame
+    dropping: bool,
+    /// Per-bit metrics of the symbols with the bit at zero and one
+    metric0: Vec<f32>,
+    metric1: Vec<f32>,
+}
+
+impl SoftDemapper {
+    pub fn new(
+        framesize: Framesize,
+        rate: CodeRate,
+        constellation: Constellation,
+        demapping: Demapping,
+        noise_variance: f32,
+    ) -> Option<Block> {
+        let interleaving = Interleaving::dvb(framesize, rate, constellation)?;
+        let pi2 = matches!(
+            constellation,
+            Constellation::ModBpsk | Constellation::ModBpskSf2
+        );
+        let spread = if matches!(constellation, Constellation::ModBpskSf2) {
+            2
+        } else {
+            1
+        };
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Soft_Demapper").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<f32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new()
+                .add_input("snr", Self::snr)
+                .build(),
+            Self {
+                framesize,
+                rate,
+                constellation,
+                points: constellation_points(rate, constellation)?,
+                bits: interleaving.bits,
+                demapping,
+                noise_variance,
+                pi2,
+                spread,
+                frame_len: interleaving.n / interleaving.bits * spread,
+                index: 0,
+                dropping: false,
+                metric0: vec![0.0; interleaving.bits],
+                metric1: vec![0.0; interleaving.bits],
+            },
+        ))
+    }
+
+    #[message_handler]
+    async fn snr(
+        &mut self,
+        _io: &mut WorkIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+        p: Pmt,
+    ) -> Result<Pmt> {
+        let snr = match p {
+            Pmt::F32(v) => v,
+            Pmt::F64(v) => v as f32,
+            _ => return Ok(Pmt::InvalidValue),
+        };
+        self.noise_variance = 10f32.powf(-snr / 10.0);
+        Ok(Pmt::Ok)
+    }
+
+    /// Adds the LLRs of symbol `y` to `llr`.
+    fn demap(&mut self, y: Complex32, llr: &mut [f32]) {
+        let bits = self.bits;
+        self.metric0.fill(f32::NEG_INFINITY);
+        self.metric1.fill(f32::NEG_INFINITY);
+        for (s, p) in self.points.iter().enumerate() {
+            let d = -(y - p).norm_sqr() / self.noise_variance;
+            for b in 0..bits {
+                let metric = if (s >> (bits - 1 - b)) & 1 == 0 {
+                    &mut self.metric0[b]
+                } else {
+                    &mut self.metric1[b]
+                };
+                *metric = match self.demapping {
+                    Demapping::LogMap => max_star(*metric, d),
+                    Demapping::MaxLog => metric.max(d),
+                };
+            }
+        }
+        for (l, (m0, m1)) in llr.iter_mut().zip(self.metric0.iter().zip(&self.metric1)) {
+            *l += m0 - m1;
+        }
+    }
+}
+
+#[async_trait]
+impl Kernel for SoftDemapper {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<f32>();
+        let mut tags: Vec<(usize, usize)> = sio
+            .input(0)
+            .tags()
+            .iter()
+            .filter_map(|t| match &t.tag {
+                Tag::NamedUsize(name, pls) if t.index < i.len() && name == PLS_TAG => {
+                    Some((t.index, *pls))
+                }
+                _ => None,
+            })
+            .collect();
+        tags.sort();
+        let mut tags = tags.into_iter().peekable();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        loop {
+            if let Some(&(index, pls)) = tags.peek() {
+                if index < consumed {
+                    tags.next();
+                    continue;
+                }
+                if index == consumed {
+                    if o.len() - produced < self.bits {
+                        break;
+                    }
+                    tags.next();
+                    self.dropping =
+                        !pls_matches(pls, self.framesize, self.rate, self.constellation);
+                    if self.dropping {
+                        warn!("SoftDemapper: dropping frame with PLS code {pls}");
+                    } else {
+                        self.index = 0;
+                        sio.output(0)
+                            .add_tag(produced, Tag::NamedUsize(PLS_TAG.to_string(), pls));
+                    }
+                }
+            }
+            let end = tags.peek().map_or(i.len(), |&(index, _)| index);
+            if self.dropping || (end - consumed < self.spread && end < i.len()) {
+                if consumed == end {
+                    break;
+                }
+                consumed = end;
+                continue;
+            }
+
+            let m = std::cmp::min(
+                (end - consumed) / self.spread,
+                (o.len() - produced) / self.bits,
+            );
+            if m == 0 {
+                break;
+            }
+            for (v, r) in i[consumed..consumed + m * self.spread]
+                .chunks_exact(self.spread)
+                .zip(o[produced..produced + m * self.bits].chunks_exact_mut(self.bits))
+            {
+                r.fill(0.0);
+                for &y in v {
+                    let y = if self.pi2 && self.index % 2 == 1 {
+                        Complex32::new(y.im, -y.re)
+                    } else {
+                        y
+                    };
+                    self.demap(y, r);
+                    self.index = (self.index + 1) % self.frame_len;
+                }
+            }
+            consumed += m * self.spread;
+            produced += m * self.bits;
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished = sio.input(0).finished() && i.len() - consumed < self.spread;
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use std::f32::consts::PI;
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::num_complex::Complex32;
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Kernel, MessageIo, MessageIoBuilder, StreamIo,
+    StreamIoBuilder, WorkIo,
+};
+
+use super::config::RolloffFactor;
+use super::pulse_shaper::rrc_taps;
+use crate::interpolation::interpolate;
+
+/// Largest timing correction per symbol, as a fraction of the symbol period
+const MAX_CORRECTION: f32 = 0.5;
+
+/// Raised-cosine pulse at `t` symbols, i.e. the RRC pulse after the matched filter
+fn raised_cosine(rolloff: f32, t: f32) -> f32 {
+    let sinc = |x: f32| {
+        if x == 0.0 {
+            1.0
+        } else {
+            (PI * x).sin() / (PI * x)
+        }
+    };
+    if (2.0 * rolloff * t).abs() == 1.0 {
+        PI / 4.0 * sinc(1.0 / (2.0 * rolloff))
+    } else {
+        sinc(t) * (PI * rolloff * t).cos() / (1.0 - (2.0 * rolloff * t).powi(2))
+    }
+}
+
+/// Slope of the mean Gardner error over the timing offset in symbols for unit-power symbols
+fn gardner_gain(rolloff: f32) -> f32 {
+    let mean_error = |u: f32| -> f32 {
+        (-32..32)
+            .map(|m| m as f32)
+            .map(|m| {
+                raised_cosine(rolloff, m - 0.5 + u)
+                    * (raised_cosine(rolloff, m - 1.0 + u) - raised_cosine(rolloff, m + u))
+            })
+            .sum()
+    };
+    let du = 1e-3;
+    (mean_error(du) - mean_error(-du)) / (2.0 * du)
+}
+
+/// Matched RRC filter and symbol timing recovery.
+///
+/// Takes `sps` samples per symbol and outputs one sample per symbol. The symbol strobes are
+/// interpolated from the matched filter output with a cubic Lagrange interpolator whose timing is
+/// driven by a Gardner timing error detector and a proportional-integral loop filter. The loop
+/// bandwidth is normalized to the symbol rate.
+pub struct SymbolSync {
+    taps: Vec<f32>,
+    /// Most recent input sample first
+    history: Vec<Complex32>,
+    /// Matched filter output not yet behind the symbol strobes
+    filtered: Vec<Complex32>,
+    sps: f32,
+    /// Time of the next strobe in samples, relative to `filtered[0]`
+    strobe: f32,
+    previous: Complex32,
+    kp: f32,
+    ki: f32,
+    /// Integrator of the loop filter, the estimated clock offset in symbols per symbol
+    integrator: f32,
+}
+
+impl SymbolSync {
+    pub fn new(
+        rolloff: RolloffFactor,
+        sps: usize,
+        span: usize,
+        loop_bandwidth: f32,
+        damping: f32,
+    ) -> Option<Block> {
+        if sps < 2 || span == 0 || loop_bandwidth <= 0.0 || damping <= 0.0 {
+            return None;
+        }
+        let rolloff = rolloff.value()?;
+        // Unit-power symbols at the strobes
+        let taps: Vec<f32> = rrc_taps(rolloff, sps, span)
+            .into_iter()
+            .map(|h| h / sps as f32)
+            .collect();
+
+        let theta = loop_bandwidth / (damping + 1.0 / (4.0 * damping));
+        let d = 1.0 + 2.0 * damping * theta + theta * theta;
+        let gain = gardner_gain(rolloff);
+        Some(Block::new(
+            BlockMetaBuilder::new("DVB_Symbol_Sync").build(),
+            StreamIoBuilder::new()
+                .add_input::<Complex32>("in")
+                .add_output::<Complex32>("out")
+                .build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self {
+                history: vec![Complex32::new(0.0, 0.0); taps.len()],
+                taps,
+                filtered: Vec::new(),
+                sps: sps as f32,
+                strobe: sps as f32,
+                previous: Complex32::new(0.0, 0.0),
+                kp: 4.0 * damping * theta / d / gain,
+                ki: 4.0 * theta * theta / d / gain,
+                integrator: 0.0,
+            },
+        ))
+    }
+
+    fn filter(&mut self, x: Complex32) {
+        self.history.rotate_right(1);
+        self.history[0] = x;
+        let y = self
+            .taps
+            .iter()
+            .zip(&self.history)
+            .map(|(h, x)| x * h)
+            .sum();
+        self.filtered.push(y);
+    }
+
+    fn strobe_ready(&self) -> bool {
+        (self.strobe as usize) + 2 < self.filtered.len()
+    }
+
+    fn next_symbol(&mut self) -> Complex32 {
+        let t = self.strobe;
+        let mid = t - self.sps / 2.0;
+        let y = interpolate(&self.filtered, t as usize, t.fract());
+        let y_mid = interpolate(&self.filtered, mid as usize, mid.fract());
+
+        let error = (y_mid * (self.previous - y).conj()).re;
+        self.integrator += self.ki * error;
+        let correction = (self.kp * error + self.integrator).clamp(-MAX_CORRECTION, MAX_CORRECTION);
+        self.previous = y;
+
+        // Keep the samples needed for the next midpoint
+        self.strobe += self.sps * (1.0 - correction);
+        let drop = (self.strobe - self.sps).max(1.0) as usize - 1;
+        self.filtered.drain(..drop);
+        self.strobe -= drop as f32;
+        y
+    }
+}
+
+#[async_trait]
+impl Kernel for SymbolSync {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let i = sio.input(0).slice::<Complex32>();
+        let o = sio.output(0).slice::<Complex32>();
+        let mut consumed = 0;
+        let mut produced = 0;
+
+        'outer: loop {
+            while self.strobe_ready() {
+                if produced == o.len() {
+                    break 'outer;
+                }
+                o[produced] = self.next_symbol();
+                produced += 1;
+            }
+            if consumed == i.len() {
+                break;
+            }
+            self.filter(i[consumed]);
+            consumed += 1;
+        }
+
+        sio.input(0).consume(consumed);
+        sio.output(0).produce(produced);
+
+        io.finished = sio.input(0).finished() && consumed == i.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use futuresdr::anyhow::{anyhow, Result};
+use futuresdr::runtime::{Block, Flowgraph};
+
+use super::config::{CodeRate, Constellation, Framesize, GoldCode, RolloffFactor};
+use super::{
+    BbHeader, BbScrambler, BchEncoder, BitInterleaver, LdpcEncoder, Modulator, PhysicalLayerFramer,
+    PulseShaper,
+};
+
+/// Builder for the complete DVB-S2 transmit chain, from transport stream bytes to pulse shaped
+/// complex samples.
+#[derive(Clone, Copy)]
+pub struct Transmitter {
+    framesize: Framesize,
+    rate: CodeRate,
+    constellation: Constellation,
+    rolloff: RolloffFactor,
+    pilots: bool,
+    dummy_rate: Option<f64>,
+    gold_code: GoldCode,
+    sps: usize,
+    span: usize,
+}
+
+impl Transmitter {
+    pub fn new(framesize: Framesize, rate: CodeRate, constellation: Constellation) -> Self {
+        Self {
+            framesize,
+            rate,
+            constellation,
+            rolloff: RolloffFactor::Ro0_20,
+            pilots: true,
+            dummy_rate: None,
+            gold_code: GoldCode::default(),
+            sps: 2,
+            span: 16,
+        }
+    }
+
+    pub fn rolloff(mut self, rolloff: RolloffFactor) -> Self {
+        self.rolloff = rolloff;
+        self
+    }
+
+    pub fn pilots(mut self, pilots: bool) -> Self {
+        self.pilots = pilots;
+        self
+    }
+
+    /// Emits DUMMY PLFRAMEs whenever the input cannot keep up with `symbol_rate` symbols per
+    /// second in real time; `None`, the default, never emits them.
+    pub fn dummy_frames(mut self, symbol_rate: Option<f64>) -> Self {
+        self.dummy_rate = symbol_rate;
+        self
+    }
+
+    pub fn gold_code(mut self, gold_code: GoldCode) -> Self {
+        self.gold_code = gold_code;
+        self
+    }
+
+    /// Samples per symbol and filter span in symbols of the pulse shaper
+    pub fn shaping(mut self, sps: usize, span: usize) -> Self {
+        self.sps = sps;
+        self.span = span;
+        self
+    }
+
+    fn blocks(&self) -> Option<Vec<Block>> {
+        let (framesize, rate, constellation) = (self.framesize, self.rate, self.constellation);
+        Some(vec![
+            BbHeader::new(framesize, rate, self.rolloff)?,
+            BbScrambler::new(framesize, rate)?,
+            BchEncoder::new(framesize, rate)?,
+            LdpcEncoder::new(framesize, rate)?,
+            BitInterleaver::new(framesize, rate, constellation)?,
+            Modulator::new(framesize, rate, constellation)?,
+            PhysicalLayerFramer::new(
+                framesize,
+                rate,
+                constellation,
+                self.pilots,
+                self.dummy_rate,
+                self.gold_code,
+            )?,
+            PulseShaper::new(self.rolloff, self.sps, self.span)?,
+        ])
+    }
+
+    /// Adds the transmit chain to `fg` and returns the ids of its first and last block.
+    ///
+    /// The first block takes `u8` transport stream bytes on its `in` port, the last one
+    /// produces `Complex32` samples on its `out` port.
+    pub fn add(&self, fg: &mut Flowgraph) -> Result<(usize, usize)> {
+        let blocks = self
+            .blocks()
+            .ok_or_else(|| anyhow!("unsupported DVB-S2 transmitter configuration"))?;
+        let ids: Vec<usize> = blocks.into_iter().map(|b| fg.add_block(b)).collect();
+        for w in ids.windows(2) {
+            fg.connect_stream(w[0], "out", w[1], "in")?;
+        }
+        Ok((ids[0], ids[ids.len() - 1]))
+    }
+
+    /// Adds the transmit chain to `fg`, feeds it from port `port` of block `src` and returns the
+    /// id of the block producing the samples on its `out` port.
+    pub fn connect(&self, fg: &mut Flowgraph, src: usize, port: &str) -> Result<usize> {
+        let (input, output) = self.add(fg)?;
+        fg.connect_stream(src, port, input, "in")?;
+        Ok(output)
+    }
+}
@@ -0,0 +1,12 @@
+use futuresdr::num_complex::Complex32;
+
+/// Cubic Lagrange interpolation of `x` at `n + mu`
+pub(crate) fn interpolate(x: &[Complex32], n: usize, mu: f32) -> Complex32 {
+    let c = [
+        -mu * (mu - 1.0) * (mu - 2.0) / 6.0,
+        (mu + 1.0) * (mu - 1.0) * (mu - 2.0) / 2.0,
+        -(mu + 1.0) * mu * (mu - 2.0) / 2.0,
+        (mu + 1.0) * mu * (mu - 1.0) / 6.0,
+    ];
+    x[n - 1] * c[0] + x[n] * c[1] + x[n + 1] * c[2] + x[n + 2] * c[3]
+}
@@ -0,0 +1,7 @@
+#![allow(clippy::new_ret_no_self)]
+
+pub mod channel;
+pub mod dvb;
+pub mod random_src;
+
+mod interpolation;
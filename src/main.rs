@@ -1,10 +1,9 @@
-mod dvb;
-mod random_src;
-
 use futuresdr::anyhow::Result;
-use futuresdr::blocks::{ApplyNM, ConsoleSink, Head};
+use futuresdr::blocks::{ConsoleSink, Head};
 use futuresdr::macros::connect;
+use futuresdr::num_complex::Complex32;
 use futuresdr::runtime::{Flowgraph, Runtime};
+use futuresdr_dvbs2::{dvb, random_src};
 
 fn main() -> Result<()> {
     let mut fg = Flowgraph::new();
@@ -12,19 +11,20 @@ fn main() -> Result<()> {
     let framesize = dvb::config::Framesize::Normal;
     let rate = dvb::config::CodeRate::C8_9;
     let constellation = dvb::config::Constellation::ModQpsk;
+    let rolloff = dvb::config::RolloffFactor::Ro0_20;
+    let pilots = true;
 
     let src = random_src::RandomByteSource::new();
-    let unpack = ApplyNM::<_, u8, u8, 1, 8>::new(move |bytes, bits| {
-        let b = bytes[0];
-        for i in 0..8 {
-            bits[i] = (b >> i) & 1;
-        }
-    });
-    let head = Head::<u8>::new(57600 - 128);
-    let bch = dvb::BchEncoder::new(framesize, rate).unwrap();
-    let snk = ConsoleSink::<u8>::new("");
+    let head = Head::<u8>::new(188 * 40);
+    let snk = fg.add_block(ConsoleSink::<Complex32>::new(""));
+
+    connect!(fg, src > head);
 
-    connect!(fg, src > unpack > head > bch > snk);
+    let tx = dvb::Transmitter::new(framesize, rate, constellation)
+        .rolloff(rolloff)
+        .pilots(pilots)
+        .connect(&mut fg, head, "out")?;
+    fg.connect_stream(tx, "out", snk, "in")?;
 
     Runtime::new().run(fg)?;
 
@@ -0,0 +1,45 @@
+use futuresdr::anyhow::Result;
+use futuresdr::blocks::{VectorSink, VectorSource};
+use futuresdr::runtime::{Flowgraph, Runtime};
+use futuresdr_dvbs2::dvb::config::{CodeRate, Framesize, RolloffFactor};
+use futuresdr_dvbs2::dvb::{BbDeframer, BbHeader};
+
+const PACKETS: usize = 100;
+
+/// Inserts stray bytes between TS packets and checks that the framer re-aligns on the sync
+/// byte, so that the deframer recovers every packet.
+#[test]
+fn sync_byte_alignment() -> Result<()> {
+    let rng = fastrand::Rng::with_seed(13);
+    let packets: Vec<Vec<u8>> = (0..PACKETS)
+        .map(|_| {
+            let mut p: Vec<u8> = (0..188).map(|_| rng.u8(..)).collect();
+            p[0] = 0x47;
+            p[1] &= 0x7f;
+            p
+        })
+        .collect();
+    let mut input = Vec::new();
+    for (n, p) in packets.iter().enumerate() {
+        if n % 10 == 3 {
+            input.extend_from_slice(&[0x00, 0x12, 0x34]);
+        }
+        input.extend_from_slice(p);
+    }
+
+    let mut fg = Flowgraph::new();
+    let src = fg.add_block(VectorSource::<u8>::new(input));
+    let framer = fg.add_block(
+        BbHeader::new(Framesize::Normal, CodeRate::C1_2, RolloffFactor::Ro0_35).unwrap(),
+    );
+    let deframer = fg.add_block(BbDeframer::new(Framesize::Normal, CodeRate::C1_2).unwrap());
+    let snk = fg.add_block(VectorSink::<u8>::new(PACKETS * 188));
+    fg.connect_stream(src, "out", framer, "in")?;
+    fg.connect_stream(framer, "out", deframer, "in")?;
+    fg.connect_stream(deframer, "out", snk, "in")?;
+    let fg = Runtime::new().run(fg)?;
+
+    let out = fg.kernel::<VectorSink<u8>>(snk).unwrap().items();
+    assert_eq!(out, &packets.concat());
+    Ok(())
+}
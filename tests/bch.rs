@@ -0,0 +1,65 @@
+use futuresdr::anyhow::Result;
+use futuresdr::blocks::{Apply, VectorSink, VectorSource};
+use futuresdr::runtime::{Flowgraph, Runtime};
+use futuresdr_dvbs2::dvb::config::{CodeRate, Framesize};
+use futuresdr_dvbs2::dvb::{BchDecoder, BchEncoder};
+
+const FRAMES: usize = 3;
+
+/// Encodes random frames, flips `t` bits of every codeword and checks that the decoder recovers
+/// the information bits. `n` and `t` are the length and the correction capability of the code
+/// and `m` the degree of its Galois field.
+fn roundtrip(framesize: Framesize, rate: CodeRate, n: usize, t: usize, m: usize) -> Result<()> {
+    let k = n - t * m;
+    let rng = fastrand::Rng::with_seed(11);
+    let bits: Vec<u8> = (0..FRAMES * k).map(|_| rng.u8(..2)).collect();
+
+    let errors: Vec<usize> = (0..t).map(|e| e * (n / t) + rng.usize(..n / t)).collect();
+    let mut index = 0;
+    let corrupt = move |b: &u8| -> u8 {
+        let flip = errors.contains(&(index % n));
+        index += 1;
+        b ^ flip as u8
+    };
+
+    let mut fg = Flowgraph::new();
+    let src = fg.add_block(VectorSource::<u8>::new(bits.clone()));
+    let enc = fg.add_block(BchEncoder::new(framesize, rate).unwrap());
+    let channel = fg.add_block(Apply::new(corrupt));
+    let dec = fg.add_block(BchDecoder::new(framesize, rate).unwrap());
+    let snk = fg.add_block(VectorSink::<u8>::new(FRAMES * k));
+    fg.connect_stream(src, "out", enc, "in")?;
+    fg.connect_stream(enc, "out", channel, "in")?;
+    fg.connect_stream(channel, "out", dec, "in")?;
+    fg.connect_stream(dec, "out", snk, "in")?;
+    let fg = Runtime::new().run(fg)?;
+
+    let out = fg.kernel::<VectorSink<u8>>(snk).unwrap().items();
+    assert_eq!(out, &bits);
+    Ok(())
+}
+
+#[test]
+fn normal_t12() -> Result<()> {
+    roundtrip(Framesize::Normal, CodeRate::C1_2, 32400, 12, 16)
+}
+
+#[test]
+fn normal_t10() -> Result<()> {
+    roundtrip(Framesize::Normal, CodeRate::C2_3, 43200, 10, 16)
+}
+
+#[test]
+fn normal_t8() -> Result<()> {
+    roundtrip(Framesize::Normal, CodeRate::C8_9, 57600, 8, 16)
+}
+
+#[test]
+fn short() -> Result<()> {
+    roundtrip(Framesize::Short, CodeRate::C1_2, 7200, 12, 14)
+}
+
+#[test]
+fn medium() -> Result<()> {
+    roundtrip(Framesize::Medium, CodeRate::C1_3Medium, 10800, 12, 15)
+}
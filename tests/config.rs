@@ -0,0 +1,91 @@
+use futuresdr_dvbs2::dvb::config::{CodeRate, Constellation, Framesize, Modcod};
+
+#[test]
+fn modcod_parts() {
+    for pls in 0..=255u8 {
+        let Ok(modcod) = Modcod::try_from(pls) else {
+            continue;
+        };
+        assert_eq!(modcod.pls(pls & 1 != 0), pls);
+        if let (Some(c), Some(r), Some(f)) = (
+            modcod.constellation(),
+            modcod.code_rate(),
+            modcod.framesize(),
+        ) {
+            assert_eq!(Modcod::from_parts(c, r, f, pls & 1 != 0), Some(modcod));
+        }
+    }
+
+    let s2x = [
+        (
+            Modcod::Mc8apsk26_45L,
+            Framesize::Normal,
+            Constellation::Mod8apsk,
+            CodeRate::C104_180,
+        ),
+        (
+            Modcod::Mc16apsk3_5L,
+            Framesize::Normal,
+            Constellation::Mod8_8apsk,
+            CodeRate::C18_30,
+        ),
+        (
+            Modcod::Mc16apsk3_5S,
+            Framesize::Short,
+            Constellation::Mod16apsk,
+            CodeRate::C3_5,
+        ),
+        (
+            Modcod::Mc32apsk32_45S,
+            Framesize::Short,
+            Constellation::Mod4_8_4_16apsk,
+            CodeRate::C32_45,
+        ),
+        (
+            Modcod::Mc64apsk4_5,
+            Framesize::Normal,
+            Constellation::Mod8_16_20_20apsk,
+            CodeRate::C4_5,
+        ),
+        (
+            Modcod::Mc256apsk3_4,
+            Framesize::Normal,
+            Constellation::Mod256apsk,
+            CodeRate::C135_180,
+        ),
+    ];
+    for (modcod, f, c, r) in s2x {
+        assert_eq!(Modcod::from_parts(c, r, f, false), Some(modcod));
+        assert_eq!(Modcod::try_from(modcod.pls(true)), Ok(modcod));
+        assert_eq!(modcod.framesize(), Some(f));
+        assert_eq!(modcod.constellation(), Some(c));
+        assert_eq!(modcod.code_rate(), Some(r));
+    }
+}
+
+#[test]
+fn s2x_pls() {
+    assert_eq!(Modcod::McQpsk13_45.pls(false), 0x84);
+    assert_eq!(Modcod::try_from(0x84), Ok(Modcod::McQpsk13_45));
+    assert_eq!(Modcod::try_from(0x85), Ok(Modcod::McQpsk13_45));
+    assert_eq!(Modcod::try_from(129), Ok(Modcod::McVlsnrSet1));
+    assert_eq!(Modcod::try_from(131), Ok(Modcod::McVlsnrSet2));
+    assert_eq!(Modcod::try_from(128), Err(128));
+    let decoded = (128..=255u8)
+        .filter(|&pls| Modcod::try_from(pls).is_ok())
+        .count();
+    // Two VL-SNR codes and every normal and short S2X MODCOD with and without pilots
+    assert_eq!(decoded, 2 + 2 * 55);
+}
+
+#[test]
+fn vlsnr_needs_pilots() {
+    let (c, r, f) = (
+        Constellation::ModBpsk,
+        CodeRate::C1_3Medium,
+        Framesize::Medium,
+    );
+    assert_eq!(Modcod::from_parts(c, r, f, true), Some(Modcod::McVlsnrSet1));
+    assert_eq!(Modcod::from_parts(c, r, f, false), None);
+    assert_eq!(Modcod::McVlsnrSet1.constellation(), None);
+}
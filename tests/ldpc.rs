@@ -0,0 +1,137 @@
+#![allow(clippy::new_ret_no_self)]
+
+use futuresdr::anyhow::Result;
+use futuresdr::async_trait::async_trait;
+use futuresdr::blocks::{Apply, VectorSink, VectorSource};
+use futuresdr::runtime::{
+    Block, BlockMeta, BlockMetaBuilder, Flowgraph, Kernel, MessageIo, MessageIoBuilder, Runtime,
+    StreamIo, StreamIoBuilder, Tag, WorkIo,
+};
+use futuresdr_dvbs2::dvb::config::{CodeRate, Framesize};
+use futuresdr_dvbs2::dvb::{LdpcDecoder, LdpcEncoder, MinSum};
+
+/// Information bits of the normal rate 1/2 code
+const K: usize = 32400;
+const FRAMES: usize = 4;
+
+#[test]
+fn quantized_offset_min_sum() -> Result<()> {
+    let rng = fastrand::Rng::with_seed(3);
+    let bits: Vec<u8> = (0..FRAMES * K).map(|_| rng.u8(..2)).collect();
+
+    // BPSK over AWGN with a noise standard deviation of 0.8, LLRs in steps of 1/4
+    let noise = fastrand::Rng::with_seed(5);
+    let sigma: f32 = 0.8;
+    let quantize = move |b: &u8| -> i8 {
+        let (u1, u2) = (1.0 - noise.f32(), noise.f32());
+        let n = (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos();
+        let y = if *b == 0 { 1.0 } else { -1.0 } + sigma * n;
+        (4.0 * 2.0 * y / (sigma * sigma))
+            .round()
+            .clamp(-127.0, 127.0) as i8
+    };
+
+    let mut fg = Flowgraph::new();
+    let src = fg.add_block(VectorSource::<u8>::new(bits.clone()));
+    let enc = fg.add_block(LdpcEncoder::new(Framesize::Normal, CodeRate::C1_2).unwrap());
+    let channel = fg.add_block(Apply::new(quantize));
+    let dec = fg.add_block(
+        LdpcDecoder::<i8>::new(Framesize::Normal, CodeRate::C1_2, 50, MinSum::Offset(2.0)).unwrap(),
+    );
+    let snk = fg.add_block(VectorSink::<u8>::new(FRAMES * K));
+    fg.connect_stream(src, "out", enc, "in")?;
+    fg.connect_stream(enc, "out", channel, "in")?;
+    fg.connect_stream(channel, "out", dec, "in")?;
+    fg.connect_stream(dec, "out", snk, "in")?;
+    let fg = Runtime::new().run(fg)?;
+
+    let out = fg.kernel::<VectorSink<u8>>(snk).unwrap().items();
+    assert_eq!(out, &bits);
+    Ok(())
+}
+
+/// Sink recording the `ldpc_failed_checks` tags of the decoder
+struct FailedChecks(Vec<usize>);
+
+impl FailedChecks {
+    fn new() -> Block {
+        Block::new(
+            BlockMetaBuilder::new("FailedChecks").build(),
+            StreamIoBuilder::new().add_input::<u8>("in").build(),
+            MessageIoBuilder::<Self>::new().build(),
+            Self(Vec::new()),
+        )
+    }
+}
+
+#[async_trait]
+impl Kernel for FailedChecks {
+    async fn work(
+        &mut self,
+        io: &mut WorkIo,
+        sio: &mut StreamIo,
+        _mio: &mut MessageIo<Self>,
+        _meta: &mut BlockMeta,
+    ) -> Result<()> {
+        let n = sio.input(0).slice::<u8>().len();
+        for t in sio.input(0).tags().iter() {
+            if let Tag::NamedUsize(name, failed) = &t.tag {
+                if t.index < n && name == "ldpc_failed_checks" {
+                    self.0.push(*failed);
+                }
+            }
+        }
+        sio.input(0).consume(n);
+        io.finished = sio.input(0).finished();
+        Ok(())
+    }
+}
+
+/// Checks that the codewords of every LDPC code satisfy all its parity checks.
+#[test]
+fn parity_checks() -> Result<()> {
+    let codes = [
+        (Framesize::Normal, CodeRate::C1_4, 16200),
+        (Framesize::Normal, CodeRate::C1_3, 21600),
+        (Framesize::Normal, CodeRate::C2_5, 25920),
+        (Framesize::Normal, CodeRate::C1_2, 32400),
+        (Framesize::Normal, CodeRate::C3_5, 38880),
+        (Framesize::Normal, CodeRate::C2_3, 43200),
+        (Framesize::Normal, CodeRate::C3_4, 48600),
+        (Framesize::Normal, CodeRate::C4_5, 51840),
+        (Framesize::Normal, CodeRate::C5_6, 54000),
+        (Framesize::Normal, CodeRate::C8_9, 57600),
+        (Framesize::Normal, CodeRate::C9_10, 58320),
+        (Framesize::Short, CodeRate::C1_4, 3240),
+        (Framesize::Short, CodeRate::C1_3, 5400),
+        (Framesize::Short, CodeRate::C2_5, 6480),
+        (Framesize::Short, CodeRate::C1_2, 7200),
+        (Framesize::Short, CodeRate::C3_5, 9720),
+        (Framesize::Short, CodeRate::C2_3, 10800),
+        (Framesize::Short, CodeRate::C3_4, 11880),
+        (Framesize::Short, CodeRate::C4_5, 12600),
+        (Framesize::Short, CodeRate::C5_6, 13320),
+        (Framesize::Short, CodeRate::C8_9, 14400),
+    ];
+    let rng = fastrand::Rng::with_seed(9);
+    for (framesize, rate, k) in codes {
+        let bits: Vec<u8> = (0..2 * k).map(|_| rng.u8(..2)).collect();
+
+        let mut fg = Flowgraph::new();
+        let src = fg.add_block(VectorSource::<u8>::new(bits));
+        let enc = fg.add_block(LdpcEncoder::new(framesize, rate).unwrap());
+        let llr = fg.add_block(Apply::new(|b: &u8| if *b == 0 { 1.0f32 } else { -1.0 }));
+        let dec =
+            fg.add_block(LdpcDecoder::<f32>::new(framesize, rate, 0, MinSum::default()).unwrap());
+        let snk = fg.add_block(FailedChecks::new());
+        fg.connect_stream(src, "out", enc, "in")?;
+        fg.connect_stream(enc, "out", llr, "in")?;
+        fg.connect_stream(llr, "out", dec, "in")?;
+        fg.connect_stream(dec, "out", snk, "in")?;
+        let fg = Runtime::new().run(fg)?;
+
+        let failed = &fg.kernel::<FailedChecks>(snk).unwrap().0;
+        assert_eq!(failed, &[0, 0], "{framesize:?} {rate:?}");
+    }
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use futuresdr::anyhow::Result;
+use futuresdr::blocks::{VectorSink, VectorSource};
+use futuresdr::runtime::{Flowgraph, Runtime};
+use futuresdr_dvbs2::channel::Awgn;
+use futuresdr_dvbs2::dvb::config::{CodeRate, Constellation, Framesize};
+use futuresdr_dvbs2::dvb::{Receiver, Transmitter};
+
+const TS_PACKET_SIZE: usize = 188;
+
+/// Outcome of a loopback run
+#[derive(Debug)]
+struct Stats {
+    /// Packets without transport error flag
+    good: usize,
+    /// Packets with transport error flag
+    errors: usize,
+    /// Missing packets between the first and the last good one
+    lost: usize,
+}
+
+/// Sends `packets` numbered TS packets through the transmitter, an AWGN channel at `esn0` and
+/// `receiver`.
+fn loopback(
+    framesize: Framesize,
+    rate: CodeRate,
+    constellation: Constellation,
+    esn0: f32,
+    packets: u32,
+    receiver: Receiver,
+) -> Result<Stats> {
+    let rng = fastrand::Rng::with_seed(7);
+    let mut ts = Vec::with_capacity(packets as usize * TS_PACKET_SIZE);
+    for n in 0..packets {
+        ts.push(0x47);
+        ts.extend_from_slice(&n.to_be_bytes());
+        ts.extend((5..TS_PACKET_SIZE).map(|_| rng.u8(..)));
+    }
+
+    let mut fg = Flowgraph::new();
+    let src = fg.add_block(VectorSource::<u8>::new(ts));
+    let tx = Transmitter::new(framesize, rate, constellation).connect(&mut fg, src, "out")?;
+    let awgn = fg.add_block(Awgn::new(esn0, 2, 1).unwrap());
+    fg.connect_stream(tx, "out", awgn, "in")?;
+    let (rx, _) = receiver.connect(&mut fg, awgn, "out")?;
+    let snk = fg.add_block(VectorSink::<u8>::new(packets as usize * TS_PACKET_SIZE));
+    fg.connect_stream(rx, "out", snk, "in")?;
+    let fg = Runtime::new().run(fg)?;
+
+    let out = fg.kernel::<VectorSink<u8>>(snk).unwrap().items();
+    let mut stats = Stats {
+        good: 0,
+        errors: 0,
+        lost: 0,
+    };
+    let mut last = None;
+    for p in out.chunks_exact(TS_PACKET_SIZE) {
+        if p[1] & 0x80 != 0 {
+            stats.errors += 1;
+            continue;
+        }
+        let n = u32::from_be_bytes(p[1..5].try_into().unwrap());
+        if let Some(last) = last {
+            stats.lost += n.saturating_sub(last + 1) as usize;
+        }
+        last = Some(n);
+        stats.good += 1;
+    }
+    Ok(stats)
+}
+
+#[test]
+fn qpsk_awgn() -> Result<()> {
+    let (rate, constellation) = (CodeRate::C1_2, Constellation::ModQpsk);
+    let receiver = Receiver::new(Framesize::Normal, rate, constellation).esn0(3.0);
+    let stats = loopback(Framesize::Normal, rate, constellation, 3.0, 800, receiver)?;
+    assert!(stats.good > 700, "{stats:?}");
+    assert_eq!((stats.errors, stats.lost), (0, 0), "{stats:?}");
+    Ok(())
+}
+
+#[test]
+fn psk8_decision_directed() -> Result<()> {
+    let (rate, constellation) = (CodeRate::C3_5, Constellation::Mod8psk);
+    let receiver = Receiver::new(Framesize::Normal, rate, constellation)
+        .esn0(8.0)
+        .decision_directed(true);
+    let stats = loopback(Framesize::Normal, rate, constellation, 8.0, 800, receiver)?;
+    assert!(stats.good > 700, "{stats:?}");
+    assert_eq!((stats.errors, stats.lost), (0, 0), "{stats:?}");
+    Ok(())
+}
+
+#[test]
+fn qpsk_short() -> Result<()> {
+    let (framesize, rate, constellation) =
+        (Framesize::Short, CodeRate::C1_2, Constellation::ModQpsk);
+    let receiver = Receiver::new(framesize, rate, constellation).esn0(3.0);
+    let stats = loopback(framesize, rate, constellation, 3.0, 800, receiver)?;
+    assert!(stats.good > 700, "{stats:?}");
+    assert_eq!((stats.errors, stats.lost), (0, 0), "{stats:?}");
+    Ok(())
+}
+
+#[test]
+fn apsk16() -> Result<()> {
+    let (rate, constellation) = (CodeRate::C2_3, Constellation::Mod16apsk);
+    let receiver = Receiver::new(Framesize::Normal, rate, constellation).esn0(11.0);
+    let stats = loopback(Framesize::Normal, rate, constellation, 11.0, 800, receiver)?;
+    assert!(stats.good > 700, "{stats:?}");
+    assert_eq!((stats.errors, stats.lost), (0, 0), "{stats:?}");
+    Ok(())
+}